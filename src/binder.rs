@@ -22,6 +22,28 @@ pub trait ConfigurationBinder {
     /// * `instance` - The instance to bind the configuration to
     fn bind_at<T: DeserializeOwned>(&self, key: impl AsRef<str>, instance: &mut T);
 
+    /// Attempts to create and return a structure bound to the configuration.
+    fn try_reify<T: DeserializeOwned>(&self) -> Result<T, Error>;
+
+    /// Attempts to bind the configuration to the specified instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance` - The instance to bind the configuration to
+    fn try_bind<T: DeserializeOwned>(&self, instance: &mut T) -> Result<(), Error>;
+
+    /// Attempts to bind the specified configuration section to the provided instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the configuration section to bind
+    /// * `instance` - The instance to bind the configuration to
+    fn try_bind_at<T: DeserializeOwned>(
+        &self,
+        key: impl AsRef<str>,
+        instance: &mut T,
+    ) -> Result<(), Error>;
+
     /// Gets a typed value from the configuration.
     ///
     /// # Arguments
@@ -54,6 +76,28 @@ impl ConfigurationBinder for dyn Configuration {
         }
     }
 
+    fn try_reify<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        from_config::<T>(self)
+    }
+
+    fn try_bind<T: DeserializeOwned>(&self, instance: &mut T) -> Result<(), Error> {
+        bind_config(self, instance)
+    }
+
+    fn try_bind_at<T: DeserializeOwned>(
+        &self,
+        key: impl AsRef<str>,
+        instance: &mut T,
+    ) -> Result<(), Error> {
+        let section = self.section(key.as_ref());
+
+        if section.exists() {
+            bind_config(section.deref().as_ref(), instance)
+        } else {
+            Ok(())
+        }
+    }
+
     fn get_value<T: FromStr>(&self, key: impl AsRef<str>) -> Result<Option<T>, T::Err> {
         let section = self.section(key.as_ref());
         let value = if section.exists() {
@@ -94,6 +138,28 @@ impl<C: AsRef<dyn Configuration>> ConfigurationBinder for C {
         }
     }
 
+    fn try_reify<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        from_config::<T>(self.as_ref())
+    }
+
+    fn try_bind<T: DeserializeOwned>(&self, instance: &mut T) -> Result<(), Error> {
+        bind_config(self.as_ref(), instance)
+    }
+
+    fn try_bind_at<T: DeserializeOwned>(
+        &self,
+        key: impl AsRef<str>,
+        instance: &mut T,
+    ) -> Result<(), Error> {
+        let section = self.as_ref().section(key.as_ref());
+
+        if section.exists() {
+            bind_config(section.deref().as_ref(), instance)
+        } else {
+            Ok(())
+        }
+    }
+
     fn get_value<T: FromStr>(&self, key: impl AsRef<str>) -> Result<Option<T>, T::Err> {
         let section = self.as_ref().section(key.as_ref());
         let value = if section.exists() {
@@ -19,6 +19,51 @@ pub trait ConfigurationBuilder {
     /// * `source` - The [`ConfigurationSource`](crate::ConfigurationSource) to add
     fn add(&mut self, source: Box<dyn ConfigurationSource>);
 
+    /// Inserts a configuration source at the specified position.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The zero-based position at which to insert the source
+    /// * `source` - The [`ConfigurationSource`](crate::ConfigurationSource) to insert
+    ///
+    /// # Remarks
+    ///
+    /// Sources added later take precedence over sources added earlier. A source inserted at
+    /// `index` overrides the sources before it and is overridden by the sources after it. An
+    /// `index` equal to the number of registered sources is the same as [`add`](ConfigurationBuilder::add).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of registered sources.
+    fn insert(&mut self, index: usize, source: Box<dyn ConfigurationSource>);
+
+    /// Adds a configuration source with the lowest precedence.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The [`ConfigurationSource`](crate::ConfigurationSource) to add
+    ///
+    /// # Remarks
+    ///
+    /// The source is overridden by every other registered source, including sources added later.
+    fn add_first(&mut self, source: Box<dyn ConfigurationSource>) {
+        self.insert(0, source)
+    }
+
+    /// Adds a configuration source that provides default values.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The [`ConfigurationSource`](crate::ConfigurationSource) to add
+    ///
+    /// # Remarks
+    ///
+    /// This is equivalent to [`add_first`](ConfigurationBuilder::add_first). Default values are
+    /// overridden by every other registered source.
+    fn add_defaults(&mut self, source: Box<dyn ConfigurationSource>) {
+        self.add_first(source)
+    }
+
     /// Builds [`ConfigurationRoot`](crate::ConfigurationRoot) with the keys and values from the
     /// registered [`ConfigurationSource`](crate::ConfigurationSource) set.
     fn build(&self) -> Result<Box<dyn ConfigurationRoot>, ReloadError>;
@@ -1,9 +1,17 @@
 use crate::{
-    util::*, ConfigurationBuilder, ConfigurationProvider, ConfigurationSource, LoadResult, Value,
+    util::*, ConfigurationBuilder, ConfigurationProvider, ConfigurationSource, LoadError,
+    LoadResult, Value,
 };
 use std::borrow::Cow;
 use std::collections::HashMap;
 
+fn undefined_short_switch(switch: &str) -> LoadError {
+    LoadError::Generic(format!(
+        "The short switch '{}' is not defined in the switch mappings.",
+        switch
+    ))
+}
+
 /// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) that
 /// provides command line configuration values.
 pub struct CommandLineConfigurationProvider {
@@ -23,7 +31,8 @@ impl CommandLineConfigurationProvider {
     /// # Remarks
     ///
     /// Only switch mapping keys that start with `--` or `-` are acceptable. Command
-    /// line arguments may start with `--`, `-`, or `/`
+    /// line arguments may start with `--`, `-`, or `/`. An argument that starts with
+    /// `-` must have a corresponding switch mapping or loading will fail.
     pub fn new(args: Vec<String>, switch_mappings: HashMap<String, String>) -> Self {
         Self {
             data: Default::default(),
@@ -71,7 +80,7 @@ impl ConfigurationProvider for CommandLineConfigurationProvider {
                 key = if let Some(mapping) = self.switch_mappings.get(&segment) {
                     mapping.clone()
                 } else if start == 1 {
-                    continue;
+                    return Err(undefined_short_switch(&current[..separator]));
                 } else {
                     current
                         .chars()
@@ -88,8 +97,8 @@ impl ConfigurationProvider for CommandLineConfigurationProvider {
 
                 key = if let Some(mapping) = self.switch_mappings.get(&current.to_uppercase()) {
                     mapping.clone()
-                } else if start == 0 {
-                    continue;
+                } else if start == 1 {
+                    return Err(undefined_short_switch(&current));
                 } else {
                     current.chars().skip(start).collect()
                 };
@@ -258,6 +267,10 @@ mod tests {
             unimplemented!()
         }
 
+        fn insert(&mut self, _index: usize, _source: Box<dyn ConfigurationSource>) {
+            unimplemented!()
+        }
+
         fn build(&self) -> Result<Box<dyn crate::ConfigurationRoot>, crate::ReloadError> {
             unimplemented!()
         }
@@ -425,19 +438,37 @@ mod tests {
     }
 
     #[test]
-    fn load_should_ignore_argument_when_short_switch_is_undefined() {
+    fn load_should_fail_when_short_switch_is_undefined() {
         // arrange
         let args = ["-Key1", "Value1"].iter();
         let switch_mappings = [("-Key2", "LongKey2")];
         let source = CommandLineConfigurationSource::new(args, &switch_mappings);
         let mut provider = source.build(&TestConfigurationBuilder);
-        let mut child_keys = Vec::with_capacity(1);
 
         // act
-        provider.load().unwrap();
-        provider.child_keys(&mut child_keys, Some(""));
+        let result = provider.load();
 
         // assert
-        assert!(child_keys.is_empty());
+        assert_eq!(
+            result.err().unwrap().message(),
+            "The short switch '-Key1' is not defined in the switch mappings."
+        );
+    }
+
+    #[test]
+    fn load_should_fail_when_short_switch_with_value_is_undefined() {
+        // arrange
+        let args = ["-k=Value1"].iter();
+        let source = CommandLineConfigurationSource::from(args);
+        let mut provider = source.build(&TestConfigurationBuilder);
+
+        // act
+        let result = provider.load();
+
+        // assert
+        assert_eq!(
+            result.err().unwrap().message(),
+            "The short switch '-k' is not defined in the switch mappings."
+        );
     }
 }
@@ -1,6 +1,8 @@
 use crate::{ConfigurationPath, ConfigurationSection, Value};
 use cfg_if::cfg_if;
-use tokens::ChangeToken;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::{Display, Formatter, Result as FormatResult};
+use tokens::{ChangeToken, Registration};
 
 cfg_if! {
     if #[cfg(feature = "async")] {
@@ -22,6 +24,16 @@ cfg_if! {
             /// Returns a [`ChangeToken`](tokens::ChangeToken) that can be used to observe when this configuration is reloaded.
             fn reload_token(&self) -> Box<dyn ChangeToken>;
 
+            /// Returns a [`ChangeToken`](tokens::ChangeToken) that signals each time this configuration is reloaded.
+            ///
+            /// # Remarks
+            ///
+            /// Unlike the [reload token](Configuration::reload_token), the token can signal more than once. The
+            /// default implementation returns the reload token.
+            fn change_token(&self) -> Box<dyn ChangeToken> {
+                self.reload_token()
+            }
+
             /// Attempts to convert the [`Configuration`] as a [`ConfigurationSection`](crate::ConfigurationSection).
             fn as_section(&self) -> Option<&dyn ConfigurationSection> {
                 None
@@ -53,6 +65,16 @@ cfg_if! {
             /// Returns a [`ChangeToken`](tokens::ChangeToken) that can be used to observe when this configuration is reloaded.
             fn reload_token(&self) -> Box<dyn ChangeToken>;
 
+            /// Returns a [`ChangeToken`](tokens::ChangeToken) that signals each time this configuration is reloaded.
+            ///
+            /// # Remarks
+            ///
+            /// Unlike the [reload token](Configuration::reload_token), the token can signal more than once. The
+            /// default implementation returns the reload token.
+            fn change_token(&self) -> Box<dyn ChangeToken> {
+                self.reload_token()
+            }
+
             /// Attempts to convert the [`Configuration`] as a [`ConfigurationSection`](crate::ConfigurationSection).
             fn as_section(&self) -> Option<&dyn ConfigurationSection> {
                 None
@@ -68,6 +90,203 @@ cfg_if! {
     }
 }
 
+/// Represents the error that occurs when required configuration is missing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MissingConfigError {
+    path: String,
+}
+
+impl MissingConfigError {
+    /// Initializes a new missing configuration error.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the missing configuration
+    pub fn new<S: Into<String>>(path: S) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Gets the path of the missing configuration.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl Display for MissingConfigError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FormatResult {
+        write!(
+            formatter,
+            "The required configuration section '{}' does not exist.",
+            &self.path
+        )
+    }
+}
+
+impl std::error::Error for MissingConfigError {}
+
+/// Represents the error that occurs when a configuration value cannot be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    key: String,
+    value: String,
+    kind: &'static str,
+}
+
+impl ParseError {
+    /// Initializes a new parse error.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value that could not be parsed
+    /// * `value` - The raw value that could not be parsed
+    /// * `kind` - The name of the type the value was parsed as
+    pub fn new<K: Into<String>, V: Into<String>>(key: K, value: V, kind: &'static str) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+            kind,
+        }
+    }
+
+    /// Gets the key of the value that could not be parsed.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Gets the raw value that could not be parsed.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FormatResult {
+        write!(
+            formatter,
+            "The value '{}' for key '{}' is not a valid {}.",
+            &self.value, &self.key, self.kind
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Represents the differences between two [`Configuration`] instances.
+///
+/// # Remarks
+///
+/// Each set of differences is ordered by key.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigurationDiff {
+    added: Vec<(String, String)>,
+    removed: Vec<(String, String)>,
+    changed: Vec<(String, String, String)>,
+}
+
+impl ConfigurationDiff {
+    /// Gets the key/value pairs that only exist in the other configuration.
+    pub fn added(&self) -> &[(String, String)] {
+        &self.added
+    }
+
+    /// Gets the key/value pairs that only exist in the original configuration.
+    pub fn removed(&self) -> &[(String, String)] {
+        &self.removed
+    }
+
+    /// Gets the keys whose values differ along with the original and other value.
+    pub fn changed(&self) -> &[(String, String, String)] {
+        &self.changed
+    }
+
+    /// Gets a value indicating whether the configurations are the same.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Represents a registration for configuration change notifications.
+///
+/// # Remarks
+///
+/// When the registration is dropped, the callback is no longer invoked.
+pub struct ChangeRegistration {
+    _token: Box<dyn ChangeToken>,
+    _registration: Registration,
+}
+
+// keys are compared case-insensitively and keys which only contain other keys are ignored; values
+// are compared as they are supplied, but reported as they are iterated so they remain redacted
+fn diff(configuration: &dyn Configuration, other: &dyn Configuration) -> ConfigurationDiff {
+    let entries = |configuration: &dyn Configuration| {
+        let displayed: HashMap<_, _> = configuration
+            .iter(Some(ConfigurationPath::Relative))
+            .map(|(key, value)| (key.to_uppercase(), value))
+            .collect();
+
+        leaves(ConfigurationIterator::new(configuration, ConfigurationPath::Relative))
+            .map(|(key, value)| {
+                let display = displayed
+                    .get(&key.to_uppercase())
+                    .map_or_else(|| value.to_string(), |value| value.to_string());
+                (key, value, display)
+            })
+            .collect::<Vec<_>>()
+    };
+    let mut theirs: BTreeMap<_, _> = entries(other)
+        .into_iter()
+        .map(|entry| (entry.0.to_uppercase(), entry))
+        .collect();
+    let mut result = ConfigurationDiff::default();
+    let mut ours = entries(configuration);
+
+    ours.sort_by_key(|(key, _, _)| key.to_uppercase());
+
+    for (key, value, display) in ours {
+        match theirs.remove(&key.to_uppercase()) {
+            Some((_, other, other_display)) if other != value => {
+                result.changed.push((key, display, other_display))
+            }
+            Some(_) => {}
+            None => result.removed.push((key, display)),
+        }
+    }
+
+    result.added = theirs
+        .into_values()
+        .map(|(key, _, display)| (key, display))
+        .collect();
+    result
+}
+
+fn parse_bool(value: &str) -> Option<bool> {
+    const TRUE: [&str; 3] = ["true", "1", "yes"];
+    const FALSE: [&str; 3] = ["false", "0", "no"];
+
+    if TRUE.iter().any(|v| v.eq_ignore_ascii_case(value)) {
+        Some(true)
+    } else if FALSE.iter().any(|v| v.eq_ignore_ascii_case(value)) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+// values are trimmed and a missing, empty, or whitespace value is None
+fn parse_value<T>(
+    configuration: &dyn Configuration,
+    key: &str,
+    kind: &'static str,
+    parse: impl FnOnce(&str) -> Option<T>,
+) -> Result<Option<T>, ParseError> {
+    match configuration.get(key) {
+        Some(value) if !value.trim().is_empty() => parse(value.trim())
+            .map(Some)
+            .ok_or_else(|| ParseError::new(key, value.as_str(), kind)),
+        _ => Ok(None),
+    }
+}
+
 /// Represents an iterator of key/value pairs for a [`Configuration`].
 pub struct ConfigurationIterator {
     stack: Vec<Box<dyn ConfigurationSection>>,
@@ -127,3 +346,311 @@ impl Iterator for ConfigurationIterator {
         None
     }
 }
+
+fn leaves(
+    entries: impl Iterator<Item = (String, Value)>,
+) -> impl Iterator<Item = (String, Value)> {
+    let mut seen = HashSet::new();
+    let mut parents = HashSet::new();
+    let delimiter = ConfigurationPath::key_delimiter();
+    let entries: Vec<_> = entries
+        .filter(|(key, _)| seen.insert(key.to_uppercase()))
+        .collect();
+
+    for (key, _) in &entries {
+        let key = key.to_uppercase();
+
+        for (index, _) in key.match_indices(delimiter) {
+            parents.insert(key[..index].to_owned());
+        }
+    }
+
+    // a key with children is only a container unless it also has a value
+    entries
+        .into_iter()
+        .filter(move |(key, value)| !value.is_empty() || !parents.contains(&key.to_uppercase()))
+}
+
+pub mod ext {
+
+    use super::*;
+    use crate::ext::ConfigurationSectionExtensions;
+
+    /// Defines extension methods for [`Configuration`].
+    pub trait ConfigurationExtensions {
+        /// Gets the connection string with the specified name.
+        ///
+        /// # Arguments
+        ///
+        /// * `name` - The name of the connection string
+        ///
+        /// # Remarks
+        ///
+        /// Connection strings are read from the `ConnectionStrings` configuration section.
+        fn connection_string(&self, name: &str) -> Option<Value>;
+
+        /// Gets every key/value pair in the [`Configuration`] ordered by key.
+        ///
+        /// # Remarks
+        ///
+        /// Keys are absolute paths and each value is the value that would be returned by
+        /// [`get`](Configuration::get). Keys which only contain other keys are excluded.
+        /// Keys are compared case-insensitively, which matches how they are resolved. A
+        /// [`BTreeMap`](std::collections::BTreeMap) can only order [`String`] keys ordinally,
+        /// so the pairs are returned as a sorted list instead.
+        fn to_sorted_pairs(&self) -> Vec<(String, String)>;
+
+        /// Gets an iterator of the key/value pairs in the [`Configuration`] that hold a value.
+        ///
+        /// # Arguments
+        ///
+        /// * `make_relative` - Indicates whether the keys should be relative to the current configuration
+        /// * `include_empty` - Indicates whether keys without children and with an empty value are included
+        ///
+        /// # Remarks
+        ///
+        /// Keys which only contain other keys are excluded, but a key that has children and also holds
+        /// a value is included.
+        fn leaves(
+            &self,
+            make_relative: bool,
+            include_empty: bool,
+        ) -> Box<dyn Iterator<Item = (String, Value)>>;
+
+        /// Compares the [`Configuration`] to another configuration.
+        ///
+        /// # Arguments
+        ///
+        /// * `other` - The [`Configuration`] to compare to
+        ///
+        /// # Remarks
+        ///
+        /// Keys are relative to each configuration and compared case-insensitively. Values are compared
+        /// as strings exactly as they are supplied, but they are reported as each configuration
+        /// [iterates](Configuration::iter) them so that redacted values are not revealed. Keys which
+        /// only contain other keys are ignored. A key with an empty value is
+        /// considered to exist, so a key that is empty in one configuration and missing in the other is
+        /// reported as added or removed.
+        fn diff(&self, other: &dyn Configuration) -> ConfigurationDiff;
+
+        /// Gets a [`ConfigurationSection`] with the specified key, which must exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `key` - The key of the configuration section
+        ///
+        /// # Remarks
+        ///
+        /// A configuration section exists if it has a value or at least one child.
+        fn get_required_section(
+            &self,
+            key: &str,
+        ) -> Result<Box<dyn ConfigurationSection>, MissingConfigError>;
+
+        /// Gets the configuration value with the specified key as a [`bool`].
+        ///
+        /// # Arguments
+        ///
+        /// * `key` - The configuration key
+        ///
+        /// # Remarks
+        ///
+        /// The values `true`, `false`, `1`, `0`, `yes`, and `no` are accepted without regard to case.
+        /// Values are trimmed and a missing or empty value returns [`None`].
+        fn get_bool(&self, key: &str) -> Result<Option<bool>, ParseError>;
+
+        /// Gets the configuration value with the specified key as an [`i64`].
+        ///
+        /// # Arguments
+        ///
+        /// * `key` - The configuration key
+        ///
+        /// # Remarks
+        ///
+        /// Values are trimmed and a missing or empty value returns [`None`].
+        fn get_i64(&self, key: &str) -> Result<Option<i64>, ParseError>;
+
+        /// Gets the configuration value with the specified key as a [`u64`].
+        ///
+        /// # Arguments
+        ///
+        /// * `key` - The configuration key
+        ///
+        /// # Remarks
+        ///
+        /// Values are trimmed and a missing or empty value returns [`None`].
+        fn get_u64(&self, key: &str) -> Result<Option<u64>, ParseError>;
+
+        /// Gets the configuration value with the specified key as an [`f64`].
+        ///
+        /// # Arguments
+        ///
+        /// * `key` - The configuration key
+        ///
+        /// # Remarks
+        ///
+        /// Values are trimmed and a missing or empty value returns [`None`].
+        fn get_f64(&self, key: &str) -> Result<Option<f64>, ParseError>;
+
+        /// Gets the configuration value with the specified key as a [`String`].
+        ///
+        /// # Arguments
+        ///
+        /// * `key` - The configuration key
+        ///
+        /// # Remarks
+        ///
+        /// Values are trimmed and a missing or empty value returns [`None`].
+        fn get_string(&self, key: &str) -> Result<Option<String>, ParseError>;
+    }
+
+    impl ConfigurationExtensions for dyn Configuration {
+        fn connection_string(&self, name: &str) -> Option<Value> {
+            self.get(&ConfigurationPath::combine(&["ConnectionStrings", name]))
+        }
+
+        fn to_sorted_pairs(&self) -> Vec<(String, String)> {
+            let mut map: Vec<_> = leaves(self.iter(Some(ConfigurationPath::Absolute)))
+                .map(|(key, value)| (key, value.to_string()))
+                .collect();
+
+            map.sort_by_cached_key(|(key, _)| key.to_uppercase());
+            map
+        }
+
+        fn leaves(
+            &self,
+            make_relative: bool,
+            include_empty: bool,
+        ) -> Box<dyn Iterator<Item = (String, Value)>> {
+            let path = if make_relative {
+                ConfigurationPath::Relative
+            } else {
+                ConfigurationPath::Absolute
+            };
+
+            Box::new(
+                leaves(self.iter(Some(path)))
+                    .filter(move |(_, value)| include_empty || !value.is_empty()),
+            )
+        }
+
+        fn diff(&self, other: &dyn Configuration) -> ConfigurationDiff {
+            diff(self, other)
+        }
+
+        fn get_required_section(
+            &self,
+            key: &str,
+        ) -> Result<Box<dyn ConfigurationSection>, MissingConfigError> {
+            let section = self.section(key);
+
+            if section.exists() {
+                Ok(section)
+            } else {
+                Err(MissingConfigError::new(section.path()))
+            }
+        }
+
+        fn get_bool(&self, key: &str) -> Result<Option<bool>, ParseError> {
+            parse_value(self, key, "bool", parse_bool)
+        }
+
+        fn get_i64(&self, key: &str) -> Result<Option<i64>, ParseError> {
+            parse_value(self, key, "i64", |v| v.parse().ok())
+        }
+
+        fn get_u64(&self, key: &str) -> Result<Option<u64>, ParseError> {
+            parse_value(self, key, "u64", |v| v.parse().ok())
+        }
+
+        fn get_f64(&self, key: &str) -> Result<Option<f64>, ParseError> {
+            parse_value(self, key, "f64", |v| v.parse().ok())
+        }
+
+        fn get_string(&self, key: &str) -> Result<Option<String>, ParseError> {
+            parse_value(self, key, "String", |v| Some(v.to_owned()))
+        }
+    }
+
+    /// Defines extension methods to observe changes to a [`Configuration`].
+    pub trait ConfigurationChangeExtensions {
+        /// Registers a callback that is invoked each time the configuration is reloaded.
+        ///
+        /// # Arguments
+        ///
+        /// * `callback` - The callback to invoke when the configuration changes
+        ///
+        /// # Remarks
+        ///
+        /// The callback is registered with the [change token](Configuration::change_token), which may
+        /// only signal once if the configuration does not support signaling more than once. A change
+        /// reported by a provider is only observed again after the [reload token](Configuration::reload_token)
+        /// has been requested or the configuration has been reloaded. The callback is unregistered when the
+        /// returned [`ChangeRegistration`] is dropped.
+        #[must_use]
+        fn on_change<F: FnMut() + Send + 'static>(&self, callback: F) -> ChangeRegistration;
+    }
+
+    impl<C: Configuration + ?Sized> ConfigurationChangeExtensions for C {
+        fn on_change<F: FnMut() + Send + 'static>(&self, callback: F) -> ChangeRegistration {
+            let callback = std::sync::Mutex::new(callback);
+            let token = self.change_token();
+            let registration = token.register(Box::new(move |_| (callback.lock().unwrap())()), None);
+
+            ChangeRegistration {
+                _token: token,
+                _registration: registration,
+            }
+        }
+    }
+
+    impl<C: AsRef<dyn Configuration>> ConfigurationExtensions for C {
+        fn connection_string(&self, name: &str) -> Option<Value> {
+            self.as_ref().connection_string(name)
+        }
+
+        fn to_sorted_pairs(&self) -> Vec<(String, String)> {
+            self.as_ref().to_sorted_pairs()
+        }
+
+        fn leaves(
+            &self,
+            make_relative: bool,
+            include_empty: bool,
+        ) -> Box<dyn Iterator<Item = (String, Value)>> {
+            self.as_ref().leaves(make_relative, include_empty)
+        }
+
+        fn diff(&self, other: &dyn Configuration) -> ConfigurationDiff {
+            self.as_ref().diff(other)
+        }
+
+        fn get_required_section(
+            &self,
+            key: &str,
+        ) -> Result<Box<dyn ConfigurationSection>, MissingConfigError> {
+            self.as_ref().get_required_section(key)
+        }
+
+        fn get_bool(&self, key: &str) -> Result<Option<bool>, ParseError> {
+            self.as_ref().get_bool(key)
+        }
+
+        fn get_i64(&self, key: &str) -> Result<Option<i64>, ParseError> {
+            self.as_ref().get_i64(key)
+        }
+
+        fn get_u64(&self, key: &str) -> Result<Option<u64>, ParseError> {
+            self.as_ref().get_u64(key)
+        }
+
+        fn get_f64(&self, key: &str) -> Result<Option<f64>, ParseError> {
+            self.as_ref().get_f64(key)
+        }
+
+        fn get_string(&self, key: &str) -> Result<Option<String>, ParseError> {
+            self.as_ref().get_string(key)
+        }
+    }
+}
@@ -1,16 +1,23 @@
-use crate::{util::fmt_debug_view, *};
+use crate::{
+    util::{fmt_debug_view, fmt_redacted_debug_view},
+    *,
+};
 use cfg_if::cfg_if;
 use std::any::Any;
 use std::borrow::Borrow;
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Formatter, Result as FormatResult};
 use std::ops::Deref;
-use tokens::{ChangeToken, CompositeChangeToken, SharedChangeToken};
+use std::iter::once;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use tokens::{
+    ChangeToken, CompositeChangeToken, DefaultChangeToken, Registration, SharedChangeToken,
+    SingleChangeToken,
+};
 
 cfg_if! {
     if #[cfg(feature = "async")] {
-        use std::sync::Arc;
-
         type Pc<T> = std::sync::Arc<T>;
         type Mut<T> = std::sync::RwLock<T>;
         type Ref<'a, T> = Arc<std::sync::RwLockReadGuard<'a, T>>;
@@ -137,11 +144,122 @@ impl DoubleEndedIterator for ProviderIter<'_> {
 
 impl<'a> ConfigurationProviderIterator<'a> for ProviderIter<'a> {}
 
+fn new_token(
+    providers: impl Iterator<Item = Box<dyn ChangeToken>>,
+) -> SharedChangeToken<CompositeChangeToken> {
+    // a composite token only advertises callback support if at least one of its tokens
+    // does; the root always signals through callbacks when it is reloaded so include a
+    // token that never requires polling
+    let root: Box<dyn ChangeToken> = Box::new(SingleChangeToken::default());
+    SharedChangeToken::new(CompositeChangeToken::new(once(root).chain(providers)))
+}
+
+// signals each time a root changes, which includes the changes reported by its providers
+#[derive(Default)]
+struct ChangeNotifier {
+    token: SharedChangeToken<DefaultChangeToken>,
+    registration: Mutex<Registration>,
+    suspended: AtomicBool,
+}
+
+// forwards a change of the current reload token of a root to its change notifier; the token is
+// only renewed from the providers when it is next requested or the root is reloaded because the
+// providers cannot be read from the thread where a provider reported its change
+#[derive(Clone)]
+struct Watcher {
+    notifier: Weak<ChangeNotifier>,
+}
+
+impl Watcher {
+    fn watch(self, token: &SharedChangeToken<CompositeChangeToken>) {
+        if let Some(notifier) = self.notifier.upgrade() {
+            let registration = token.register(Box::new(move |_| self.changed()), None);
+            *notifier.registration.lock().unwrap() = registration;
+        }
+    }
+
+    fn changed(&self) {
+        let Some(notifier) = self.notifier.upgrade() else {
+            return;
+        };
+
+        // a reload notifies listeners itself once it completes
+        if !notifier.suspended.load(Ordering::SeqCst) {
+            notifier.token.notify();
+        }
+    }
+}
+
+// the maximum number of nested references followed while substituting a value
+const MAX_SUBSTITUTION_DEPTH: usize = 8;
+
+#[derive(Clone, Default)]
+struct RootOptions {
+    substitution: bool,
+    reload_policy: ReloadPolicy,
+    redaction: Option<Pc<RedactionLayer>>,
+    aliases: HashMap<String, (String, String)>,
+    required_keys: Vec<String>,
+}
+
+// follows each alias to its target and returns the chain of keys that loops back, if any
+fn find_alias_cycle(aliases: &HashMap<String, (String, String)>) -> Option<Vec<String>> {
+    let mut keys: Vec<_> = aliases.keys().collect();
+
+    // check in a stable order so the same cycle is always reported the same way
+    keys.sort();
+
+    for key in keys {
+        let mut chain: Vec<&str> = Vec::new();
+        let mut next = key.clone();
+
+        while let Some((alias, target)) = aliases.get(&next) {
+            if let Some(start) = chain.iter().position(|k| k.eq_ignore_ascii_case(alias)) {
+                let mut cycle: Vec<_> = chain[start..].iter().map(|k| (*k).to_owned()).collect();
+                cycle.push(alias.clone());
+                return Some(cycle);
+            }
+
+            chain.push(alias);
+            next = target.to_uppercase();
+        }
+    }
+
+    None
+}
+
+fn redact(
+    iter: ConfigurationIterator,
+    redaction: Option<Pc<RedactionLayer>>,
+    parent_path: Option<String>,
+) -> Box<dyn Iterator<Item = (String, Value)>> {
+    if let Some(redaction) = redaction {
+        Box::new(iter.map(move |(key, value)| {
+            let value = if let Some(ref path) = parent_path {
+                redaction.redact(&ConfigurationPath::combine(&[path, &key]), value)
+            } else {
+                redaction.redact(&key, value)
+            };
+
+            (key, value)
+        }))
+    } else {
+        Box::new(iter)
+    }
+}
+
 /// Represents the root of a configuration.
+///
+/// # Remarks
+///
+/// Each clone shares the same providers. When the **async** feature is enabled, the root is `Send`
+/// and `Sync` so it can be shared across threads.
 #[derive(Clone)]
 pub struct DefaultConfigurationRoot {
-    token: SharedChangeToken<CompositeChangeToken>,
+    token: Pc<Mut<SharedChangeToken<CompositeChangeToken>>>,
     providers: Pc<Mut<Vec<Box<dyn ConfigurationProvider>>>>,
+    notifier: Arc<ChangeNotifier>,
+    options: RootOptions,
 }
 
 impl DefaultConfigurationRoot {
@@ -150,9 +268,19 @@ impl DefaultConfigurationRoot {
     /// # Arguments
     ///
     /// * `providers` - The [`ConfigurationProvider`](crate::ConfigurationProvider) list used in the configuration
-    pub fn new(mut providers: Vec<Box<dyn ConfigurationProvider>>) -> Result<Self, ReloadError> {
+    pub fn new(providers: Vec<Box<dyn ConfigurationProvider>>) -> Result<Self, ReloadError> {
+        Self::with_options(providers, Default::default())
+    }
+
+    fn with_options(
+        mut providers: Vec<Box<dyn ConfigurationProvider>>,
+        options: RootOptions,
+    ) -> Result<Self, ReloadError> {
+        if let Some(cycle) = find_alias_cycle(&options.aliases) {
+            return Err(ReloadError::AliasCycle(cycle));
+        }
+
         let mut errors = Vec::new();
-        let mut tokens = Vec::with_capacity(providers.len());
 
         for provider in providers.iter_mut() {
             let result = provider.load();
@@ -160,18 +288,164 @@ impl DefaultConfigurationRoot {
             if let Err(error) = result {
                 errors.push((provider.name().to_owned(), error));
             }
+        }
 
-            tokens.push(provider.reload_token());
+        if !errors.is_empty() {
+            return Err(ReloadError::Provider(errors));
         }
 
-        if errors.is_empty() {
-            Ok(Self {
-                token: SharedChangeToken::new(CompositeChangeToken::new(tokens.into_iter())),
-                providers: Pc::new(providers.into()),
-            })
+        let root = Self {
+            token: Pc::new(new_token(providers.iter().map(|p| p.reload_token())).into()),
+            providers: Pc::new(Mut::new(providers)),
+            notifier: Default::default(),
+            options,
+        };
+
+        cfg_if! {
+            if #[cfg(feature = "async")] {
+                root.watcher().watch(&root.token.read().unwrap());
+            } else {
+                root.watcher().watch(&root.token.deref().borrow());
+            }
+        }
+
+        let missing: Vec<_> = root
+            .options
+            .required_keys
+            .iter()
+            .filter(|key| root.get(key).is_none_or(|value| value.trim().is_empty()))
+            .cloned()
+            .collect();
+
+        if missing.is_empty() {
+            Ok(root)
+        } else {
+            Err(ReloadError::MissingKeys(missing))
+        }
+    }
+
+    fn watcher(&self) -> Watcher {
+        Watcher {
+            notifier: Arc::downgrade(&self.notifier),
+        }
+    }
+
+    // changes reported by the providers while they are being loaded are not forwarded
+    fn suspend(&self, suspended: bool) {
+        self.notifier.suspended.store(suspended, Ordering::SeqCst);
+    }
+
+    fn replace_token(
+        &self,
+        new_token: SharedChangeToken<CompositeChangeToken>,
+    ) -> SharedChangeToken<CompositeChangeToken> {
+        self.watcher().watch(&new_token);
+
+        cfg_if! {
+            if #[cfg(feature = "async")] {
+                std::mem::replace(&mut *self.token.write().unwrap(), new_token)
+            } else {
+                self.token.replace(new_token)
+            }
+        }
+    }
+
+    fn notify(&self, new_token: SharedChangeToken<CompositeChangeToken>) {
+        self.replace_token(new_token).notify();
+        self.notifier.token.notify();
+    }
+
+    // changes the providers when they are not borrowed; listeners are only notified if the update
+    // reports that the providers changed
+    fn update<F>(&self, update: F) -> ReloadResult
+    where
+        F: FnOnce(&mut Vec<Box<dyn ConfigurationProvider>>) -> bool,
+    {
+        let borrowed = (Pc::strong_count(&self.providers) - 1) + Pc::weak_count(&self.providers);
+
+        cfg_if! {
+            if #[cfg(feature = "async")] {
+                let result = self.providers.try_write();
+            } else {
+                let result = self.providers.try_borrow_mut();
+            }
+        }
+
+        if let Ok(mut providers) = result {
+            self.suspend(true);
+            let updated = update(&mut providers);
+            let new_token = new_token(providers.iter().map(|p| p.reload_token()));
+            drop(providers);
+            self.suspend(false);
+
+            if updated {
+                self.notify(new_token);
+            } else {
+                self.replace_token(new_token);
+            }
+
+            Ok(())
         } else {
-            Err(ReloadError::Provider(errors))
+            Err(ReloadError::Borrowed(Some(borrowed)))
+        }
+    }
+
+    fn find(&self, key: &str) -> Option<Value> {
+        for provider in self.providers().rev() {
+            if let Some(value) = provider.get(key) {
+                return Some(value);
+            }
         }
+
+        // aliases are validated when the root is created so the chain always ends
+        let (_, target) = self.options.aliases.get(&key.to_uppercase())?;
+        self.find(target)
+    }
+
+    // replaces each ${Key} or ${ENV:NAME} reference in the value; unresolved references are
+    // left as is, $${ escapes a reference, and None is returned if the references are too deep
+    fn substitute(&self, value: &str, depth: usize) -> Option<String> {
+        let mut result = String::with_capacity(value.len());
+        let mut rest = value;
+
+        while let Some(start) = rest.find('$') {
+            result.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            if rest.starts_with("$${") {
+                result.push_str("${");
+                rest = &rest[3..];
+                continue;
+            }
+
+            let end = match rest.find('}') {
+                Some(end) if rest.starts_with("${") => end,
+                _ => {
+                    result.push('$');
+                    rest = &rest[1..];
+                    continue;
+                }
+            };
+            let name = &rest[2..end];
+
+            if name.len() > 4 && name.get(..4).is_some_and(|p| p.eq_ignore_ascii_case("ENV:")) {
+                match std::env::var(&name[4..]) {
+                    Ok(var) => result.push_str(&var),
+                    _ => result.push_str(&rest[..=end]),
+                }
+            } else if depth == MAX_SUBSTITUTION_DEPTH {
+                return None;
+            } else if let Some(value) = self.find(name) {
+                result.push_str(&self.substitute(&value, depth + 1)?);
+            } else {
+                result.push_str(&rest[..=end]);
+            }
+
+            rest = &rest[end + 1..];
+        }
+
+        result.push_str(rest);
+        Some(result)
     }
 }
 
@@ -188,23 +462,51 @@ impl ConfigurationRoot for DefaultConfigurationRoot {
         }
 
         if let Ok(mut providers) = result {
+            self.suspend(true);
+
+            let fail_fast = self.options.reload_policy == ReloadPolicy::FailFast;
             let mut errors = Vec::new();
-            let mut tokens = Vec::with_capacity(providers.len());
 
-            for provider in providers.iter_mut() {
-                let result = provider.load();
+            if fail_fast {
+                // stage every provider before any of them is committed so that the configuration
+                // never observes a mix of old and new values
+                for i in 0..providers.len() {
+                    if let Err(error) = providers[i].stage() {
+                        let error = (providers[i].name().to_owned(), error);
+                        let loaded = providers[..i].iter().map(|p| p.name().to_owned()).collect();
+
+                        for provider in providers[..i].iter_mut() {
+                            provider.discard();
+                        }
+
+                        drop(providers);
+                        self.suspend(false);
 
-                if let Err(error) = result {
-                    errors.push((provider.name().to_owned(), error));
+                        // renews the reload token if a provider reported a change before the reload
+                        self.reload_token();
+
+                        return Err(ReloadError::Stopped { loaded, error });
+                    }
                 }
 
-                tokens.push(provider.reload_token());
+                for provider in providers.iter_mut() {
+                    provider.commit();
+                }
+            } else {
+                for provider in providers.iter_mut() {
+                    if let Err(error) = provider.load() {
+                        errors.push((provider.name().to_owned(), error));
+                    }
+                }
             }
 
-            let new_token = SharedChangeToken::new(CompositeChangeToken::new(tokens.into_iter()));
-            let old_token = std::mem::replace(&mut self.token, new_token);
+            let new_token = new_token(providers.iter().map(|p| p.reload_token()));
 
-            old_token.notify();
+            // release the providers before notifying so that listeners can
+            // immediately read the configuration and request a new token
+            drop(providers);
+            self.suspend(false);
+            self.notify(new_token);
 
             if errors.is_empty() {
                 Ok(())
@@ -216,6 +518,25 @@ impl ConfigurationRoot for DefaultConfigurationRoot {
         }
     }
 
+    fn add_provider(&mut self, mut provider: Box<dyn ConfigurationProvider>) -> ReloadResult {
+        if let Err(error) = provider.load() {
+            return Err(ReloadError::Provider(vec![(provider.name().to_owned(), error)]));
+        }
+
+        self.update(|providers| {
+            providers.push(provider);
+            true
+        })
+    }
+
+    fn remove_provider(&mut self, name: &str) -> ReloadResult {
+        self.update(|providers| {
+            let count = providers.len();
+            providers.retain(|provider| provider.name() != name);
+            providers.len() != count
+        })
+    }
+
     fn providers(&self) -> Box<dyn ConfigurationProviderIterator + '_> {
         cfg_if! {
             if #[cfg(feature = "async")] {
@@ -233,20 +554,22 @@ impl ConfigurationRoot for DefaultConfigurationRoot {
 
 impl Configuration for DefaultConfigurationRoot {
     fn get(&self, key: &str) -> Option<Value> {
-        for provider in self.providers().rev() {
-            if let Some(value) = provider.get(key) {
-                return Some(value);
+        let value = self.find(key)?;
+
+        if self.options.substitution && value.contains('$') {
+            // a value whose references cannot be resolved is returned unchanged
+            if let Some(substituted) = self.substitute(&value, 0) {
+                return Some(substituted.into());
             }
         }
 
-        None
+        Some(value)
     }
 
     fn section(&self, key: &str) -> Box<dyn ConfigurationSection> {
-        Box::new(DefaultConfigurationSection::new(
-            Box::new(self.clone()),
-            key,
-        ))
+        let mut section = DefaultConfigurationSection::new(Box::new(self.clone()), key);
+        section.redaction = self.options.redaction.clone();
+        Box::new(section)
     }
 
     fn children(&self) -> Vec<Box<dyn ConfigurationSection>> {
@@ -263,20 +586,44 @@ impl Configuration for DefaultConfigurationRoot {
     }
 
     fn reload_token(&self) -> Box<dyn ChangeToken> {
-        Box::new(self.token.clone())
+        cfg_if! {
+            if #[cfg(feature = "async")] {
+                let mut token = self.token.write().unwrap();
+            } else {
+                let mut token = self.token.borrow_mut();
+            }
+        }
+
+        // provider tokens are typically single-use and replaced after each change so
+        // the composite must be rebuilt from the current tokens once it has changed
+        if token.changed() {
+            *token = new_token(self.providers().map(|p| p.reload_token()));
+            self.watcher().watch(&token);
+        }
+
+        Box::new(token.clone())
+    }
+
+    fn change_token(&self) -> Box<dyn ChangeToken> {
+        Box::new(self.notifier.token.clone())
     }
 
     fn iter(&self, path: Option<ConfigurationPath>) -> Box<dyn Iterator<Item = (String, Value)>> {
-        Box::new(ConfigurationIterator::new(
-            self,
-            path.unwrap_or(ConfigurationPath::Absolute),
-        ))
+        redact(
+            ConfigurationIterator::new(self, path.unwrap_or(ConfigurationPath::Absolute)),
+            self.options.redaction.clone(),
+            None,
+        )
     }
 }
 
 impl Debug for DefaultConfigurationRoot {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> FormatResult {
-        fmt_debug_view(self, formatter)
+        if let Some(ref redaction) = self.options.redaction {
+            fmt_redacted_debug_view(self, redaction, formatter)
+        } else {
+            fmt_debug_view(self, formatter)
+        }
     }
 }
 
@@ -312,6 +659,7 @@ cfg_if! {
 pub struct DefaultConfigurationSection {
     root: Pc<dyn ConfigurationRoot>,
     path: String,
+    redaction: Option<Pc<RedactionLayer>>,
 }
 
 impl DefaultConfigurationSection {
@@ -325,6 +673,7 @@ impl DefaultConfigurationSection {
         Self {
             root: root.into(),
             path: path.to_owned(),
+            redaction: None,
         }
     }
 
@@ -361,15 +710,27 @@ impl Configuration for DefaultConfigurationSection {
         self.root.reload_token()
     }
 
+    fn change_token(&self) -> Box<dyn ChangeToken> {
+        self.root.change_token()
+    }
+
     fn as_section(&self) -> Option<&dyn ConfigurationSection> {
         Some(self)
     }
 
     fn iter(&self, path: Option<ConfigurationPath>) -> Box<dyn Iterator<Item = (String, Value)>> {
-        Box::new(ConfigurationIterator::new(
-            self,
-            path.unwrap_or(ConfigurationPath::Absolute),
-        ))
+        let path = path.unwrap_or(ConfigurationPath::Absolute);
+        let parent_path = if path == ConfigurationPath::Relative {
+            Some(self.path.clone())
+        } else {
+            None
+        };
+
+        redact(
+            ConfigurationIterator::new(self, path),
+            self.redaction.clone(),
+            parent_path,
+        )
     }
 }
 
@@ -419,6 +780,8 @@ pub struct DefaultConfigurationBuilder {
 
     /// Gets the properties that can be passed to configuration sources.
     pub properties: HashMap<String, Box<dyn Any>>,
+
+    options: RootOptions,
 }
 
 impl DefaultConfigurationBuilder {
@@ -426,6 +789,103 @@ impl DefaultConfigurationBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Enables placeholder substitution in the built configuration.
+    ///
+    /// # Remarks
+    ///
+    /// When enabled, a value can refer to another configuration value using `${Key}` or to an
+    /// environment variable using `${ENV:NAME}`. References are resolved recursively when a value
+    /// is read. A reference that cannot be resolved is left as is and `$${` escapes a reference.
+    /// If references are nested too deeply, such as a cycle, the value is returned unchanged.
+    pub fn enable_substitution(&mut self) -> &mut Self {
+        self.options.substitution = true;
+        self
+    }
+
+    /// Sets the policy applied when a provider fails to load while the configuration is reloaded.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The [`ReloadPolicy`](crate::ReloadPolicy) to apply
+    pub fn reload_policy(&mut self, policy: ReloadPolicy) -> &mut Self {
+        self.options.reload_policy = policy;
+        self
+    }
+
+    /// Requires the specified configuration keys to have a value when the configuration is built.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The configuration keys that must have a value
+    ///
+    /// # Remarks
+    ///
+    /// Building the configuration fails with [`ReloadError::MissingKeys`](crate::ReloadError::MissingKeys),
+    /// which lists every key that is missing or only has an empty value. Required keys are not
+    /// verified when the configuration is reloaded.
+    pub fn require_keys(&mut self, keys: &[&str]) -> &mut Self {
+        self.options
+            .required_keys
+            .extend(keys.iter().map(|key| (*key).to_owned()));
+        self
+    }
+
+    /// Adds an alias for a configuration key.
+    ///
+    /// # Arguments
+    ///
+    /// * `alias` - The configuration key that is an alias
+    /// * `target` - The configuration key the alias refers to
+    ///
+    /// # Remarks
+    ///
+    /// When the alias does not have a value, the value of the target key is returned instead. The target
+    /// may itself be an alias. Aliases are not reported when enumerating child sections. Building the
+    /// configuration fails with [`ReloadError::AliasCycle`](crate::ReloadError::AliasCycle) if a chain
+    /// of aliases loops back to one of its own keys.
+    pub fn add_alias<S: AsRef<str>>(&mut self, alias: S, target: S) -> &mut Self {
+        let alias = alias.as_ref();
+        self.options.aliases.insert(
+            alias.to_uppercase(),
+            (alias.to_owned(), target.as_ref().to_owned()),
+        );
+        self
+    }
+
+    /// Redacts sensitive values when the built configuration is formatted or iterated.
+    ///
+    /// # Arguments
+    ///
+    /// * `redaction` - The [`RedactionLayer`](crate::RedactionLayer) describing the sensitive keys
+    ///
+    /// # Remarks
+    ///
+    /// Redaction applies to the [`Debug`] output and the [`iter`](crate::Configuration::iter) of the
+    /// configuration and its sections. Sensitive values are still returned by [`get`](crate::Configuration::get).
+    pub fn redact(&mut self, redaction: RedactionLayer) -> &mut Self {
+        self.options.redaction = Some(Pc::new(redaction));
+        self
+    }
+
+    /// Builds a [`DefaultConfigurationRoot`] that can be shared across threads.
+    ///
+    /// # Remarks
+    ///
+    /// With the **async** feature, the root holds its providers in an `Arc<RwLock<_>>` and is `Send`
+    /// and `Sync`. Each clone shares the same providers so a clone can be moved to another thread
+    /// while the original is reloaded. Reading a value takes the read lock and
+    /// [`reload`](crate::ConfigurationRoot::reload) takes the write lock, failing with
+    /// [`ReloadError::Borrowed`](crate::ReloadError::Borrowed) if the providers are being read at
+    /// the same time.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub fn build_shared(&self) -> Result<DefaultConfigurationRoot, ReloadError> {
+        DefaultConfigurationRoot::with_options(
+            self.sources.iter().map(|s| s.build(self)).collect(),
+            self.options.clone(),
+        )
+    }
 }
 
 impl ConfigurationBuilder for DefaultConfigurationBuilder {
@@ -441,9 +901,20 @@ impl ConfigurationBuilder for DefaultConfigurationBuilder {
         self.sources.push(source)
     }
 
+    fn insert(&mut self, index: usize, source: Box<dyn ConfigurationSource>) {
+        assert!(
+            index <= self.sources.len(),
+            "The index {} is greater than the number of configuration sources ({}).",
+            index,
+            self.sources.len()
+        );
+        self.sources.insert(index, source)
+    }
+
     fn build(&self) -> Result<Box<dyn ConfigurationRoot>, ReloadError> {
-        Ok(Box::new(DefaultConfigurationRoot::new(
+        Ok(Box::new(DefaultConfigurationRoot::with_options(
             self.sources.iter().map(|s| s.build(self)).collect(),
+            self.options.clone(),
         )?))
     }
 }
@@ -0,0 +1,343 @@
+use crate::FileSource;
+use crate::{
+    ConfigurationBuilder, ConfigurationPath, ConfigurationProvider, ConfigurationSource,
+    FileConfigurationParser, FileConfigurationProvider, LoadResult, Value,
+};
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::Chars;
+use tokens::ChangeToken;
+
+// '${NAME}' resolves a key defined earlier in the same file and then falls back
+// to the process environment; an unresolved reference is an empty string
+fn interpolate(
+    chars: &mut Chars,
+    variables: &HashMap<String, String>,
+    value: &mut String,
+) -> Result<(), String> {
+    let mut name = String::new();
+
+    for ch in chars.by_ref() {
+        if ch == '}' {
+            if let Some(other) = variables.get(&name.to_uppercase()) {
+                value.push_str(other);
+            } else if let Ok(other) = std::env::var(&name) {
+                value.push_str(&other);
+            }
+
+            return Ok(());
+        }
+
+        name.push(ch);
+    }
+
+    Err(format!("The reference '${{{}' is not terminated.", name))
+}
+
+fn is_comment(rest: &str) -> bool {
+    let rest = rest.trim();
+    rest.is_empty() || rest.starts_with('#')
+}
+
+fn double_quoted(content: &str, variables: &HashMap<String, String>) -> Result<String, String> {
+    let mut value = String::new();
+    let mut chars = content[1..].chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' => {
+                return if is_comment(chars.as_str()) {
+                    Ok(value)
+                } else {
+                    Err("Unexpected characters follow the quoted value.".into())
+                };
+            }
+            '\\' => match chars.next() {
+                Some('n') => value.push('\n'),
+                Some('r') => value.push('\r'),
+                Some('t') => value.push('\t'),
+                Some(other @ ('\\' | '"' | '\'' | '$')) => value.push(other),
+                Some(other) => return Err(format!("The escape sequence '\\{}' is invalid.", other)),
+                None => break,
+            },
+            '$' if chars.as_str().starts_with('{') => {
+                chars.next();
+                interpolate(&mut chars, variables, &mut value)?;
+            }
+            _ => value.push(ch),
+        }
+    }
+
+    Err("The quoted value is not terminated.".into())
+}
+
+fn single_quoted(content: &str) -> Result<String, String> {
+    let content = &content[1..];
+
+    match content.find('\'') {
+        Some(end) if is_comment(&content[end + 1..]) => Ok(content[..end].to_owned()),
+        Some(_) => Err("Unexpected characters follow the quoted value.".into()),
+        None => Err("The quoted value is not terminated.".into()),
+    }
+}
+
+fn unquoted(content: &str, variables: &HashMap<String, String>) -> Result<String, String> {
+    // an inline comment must be preceded by whitespace so that values such as 'a#b' are preserved
+    let end = content
+        .char_indices()
+        .find(|&(i, ch)| ch == '#' && (i == 0 || content[..i].ends_with(char::is_whitespace)))
+        .map_or(content.len(), |(i, _)| i);
+    let mut value = String::new();
+    let mut chars = content[..end].trim_end().chars();
+
+    while let Some(ch) = chars.next() {
+        if ch == '$' && chars.as_str().starts_with('{') {
+            chars.next();
+            interpolate(&mut chars, variables, &mut value)?;
+        } else {
+            value.push(ch);
+        }
+    }
+
+    Ok(value)
+}
+
+fn parse_line<'a>(
+    line: &'a str,
+    variables: &HashMap<String, String>,
+) -> Result<(&'a str, String), String> {
+    let line = line.strip_prefix("export ").map_or(line, str::trim_start);
+    let index = line
+        .find('=')
+        .ok_or_else(|| "A key must be followed by '='.".to_owned())?;
+    let key = line[..index].trim();
+
+    if key.is_empty() || key.contains(char::is_whitespace) {
+        return Err(format!("The key '{}' is invalid.", key));
+    }
+
+    let content = line[index + 1..].trim_start();
+    let value = if content.starts_with('"') {
+        double_quoted(content, variables)?
+    } else if content.starts_with('\'') {
+        single_quoted(content)?
+    } else {
+        unquoted(content, variables)?
+    };
+
+    Ok((key, value))
+}
+
+struct DotEnvParser {
+    nested_keys: bool,
+}
+
+impl FileConfigurationParser for DotEnvParser {
+    fn parse(
+        &self,
+        content: &[u8],
+        path: &Path,
+    ) -> Result<HashMap<String, (String, Value)>, String> {
+        let content = String::from_utf8(content.to_vec()).map_err(|e| {
+            format!(
+                "The configuration file '{}' could not be read. {}",
+                path.display(),
+                e
+            )
+        })?;
+        let mut variables = HashMap::new();
+        let mut data = HashMap::new();
+
+        for (index, line) in content.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = parse_line(line, &variables).map_err(|e| {
+                format!(
+                    "Could not parse the .env file '{}'. {} (Line: {})",
+                    path.display(),
+                    e,
+                    index + 1
+                )
+            })?;
+            let new_key = if self.nested_keys {
+                key.replace("__", ConfigurationPath::key_delimiter())
+            } else {
+                key.to_owned()
+            };
+
+            variables.insert(key.to_uppercase(), value.clone());
+            data.insert(new_key.to_uppercase(), (new_key, value.into()));
+        }
+
+        Ok(data)
+    }
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for `.env` files.
+pub struct DotEnvConfigurationProvider {
+    inner: FileConfigurationProvider<DotEnvParser>,
+}
+
+impl DotEnvConfigurationProvider {
+    /// Initializes a new `.env` file configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The `.env` [`FileSource`](crate::FileSource) information
+    pub fn new(file: FileSource) -> Self {
+        Self::with_nested_keys(file, true)
+    }
+
+    /// Initializes a new `.env` file configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The `.env` [`FileSource`](crate::FileSource) information
+    /// * `nested_keys` - Indicates whether a double underscore (`__`) in a key is replaced with the
+    ///   configuration key delimiter
+    pub fn with_nested_keys(file: FileSource, nested_keys: bool) -> Self {
+        Self {
+            inner: FileConfigurationProvider::new(file, DotEnvParser { nested_keys }),
+        }
+    }
+}
+
+impl ConfigurationProvider for DotEnvConfigurationProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.inner.get(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.inner.reload_token()
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.inner.load()
+    }
+
+    fn stage(&mut self) -> LoadResult {
+        self.inner.stage()
+    }
+
+    fn commit(&mut self) {
+        self.inner.commit()
+    }
+
+    fn discard(&mut self) {
+        self.inner.discard()
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.inner.child_keys(earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for `.env` files.
+pub struct DotEnvConfigurationSource {
+    file: FileSource,
+    nested_keys: bool,
+}
+
+impl DotEnvConfigurationSource {
+    /// Initializes a new `.env` file configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The `.env` [`FileSource`](crate::FileSource) information
+    pub fn new(file: FileSource) -> Self {
+        Self {
+            file,
+            nested_keys: true,
+        }
+    }
+
+    /// Sets whether a double underscore (`__`) in a key is replaced with the configuration key delimiter.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Indicates whether keys are nested; the default value is `true`
+    pub fn nested_keys(mut self, enabled: bool) -> Self {
+        self.nested_keys = enabled;
+        self
+    }
+}
+
+impl ConfigurationSource for DotEnvConfigurationSource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(DotEnvConfigurationProvider::with_nested_keys(
+            self.file.clone(),
+            self.nested_keys,
+        ))
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait DotEnvConfigurationExtensions {
+        /// Adds a `.env` file as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `file` - The `.env` [`FileSource`](crate::FileSource) information
+        ///
+        /// # Remarks
+        ///
+        /// A double underscore (`__`) in a key is replaced with the configuration key delimiter so that
+        /// keys can express nesting; for example, `Logging__Level` becomes `Logging:Level`.
+        fn add_dotenv_file<T: Into<FileSource>>(&mut self, file: T) -> &mut Self;
+
+        /// Adds a `.env` file as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `file` - The `.env` [`FileSource`](crate::FileSource) information
+        /// * `nested_keys` - Indicates whether a double underscore (`__`) in a key is replaced with the
+        ///   configuration key delimiter
+        fn add_dotenv_file_with_nested_keys<T: Into<FileSource>>(
+            &mut self,
+            file: T,
+            nested_keys: bool,
+        ) -> &mut Self;
+    }
+
+    impl DotEnvConfigurationExtensions for dyn ConfigurationBuilder {
+        fn add_dotenv_file<T: Into<FileSource>>(&mut self, file: T) -> &mut Self {
+            self.add(Box::new(DotEnvConfigurationSource::new(file.into())));
+            self
+        }
+
+        fn add_dotenv_file_with_nested_keys<T: Into<FileSource>>(
+            &mut self,
+            file: T,
+            nested_keys: bool,
+        ) -> &mut Self {
+            let source = DotEnvConfigurationSource::new(file.into()).nested_keys(nested_keys);
+            self.add(Box::new(source));
+            self
+        }
+    }
+
+    impl<T: ConfigurationBuilder> DotEnvConfigurationExtensions for T {
+        fn add_dotenv_file<F: Into<FileSource>>(&mut self, file: F) -> &mut Self {
+            self.add(Box::new(DotEnvConfigurationSource::new(file.into())));
+            self
+        }
+
+        fn add_dotenv_file_with_nested_keys<F: Into<FileSource>>(
+            &mut self,
+            file: F,
+            nested_keys: bool,
+        ) -> &mut Self {
+            let source = DotEnvConfigurationSource::new(file.into()).nested_keys(nested_keys);
+            self.add(Box::new(source));
+            self
+        }
+    }
+}
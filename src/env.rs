@@ -1,6 +1,6 @@
 use crate::{
-    util::accumulate_child_keys, ConfigurationBuilder, ConfigurationProvider, ConfigurationSource,
-    LoadResult, Value,
+    util::accumulate_child_keys, ConfigurationBuilder, ConfigurationPath, ConfigurationProvider,
+    ConfigurationSource, LoadResult, Value,
 };
 use std::collections::HashMap;
 use std::env::vars;
@@ -10,6 +10,7 @@ use std::env::vars;
 pub struct EnvironmentVariablesConfigurationProvider {
     prefix: String,
     data: HashMap<String, (String, Value)>,
+    staged: Option<HashMap<String, (String, Value)>>,
 }
 
 impl EnvironmentVariablesConfigurationProvider {
@@ -22,32 +23,53 @@ impl EnvironmentVariablesConfigurationProvider {
         Self {
             prefix,
             data: HashMap::with_capacity(0),
+            staged: None,
         }
     }
-}
-
-impl ConfigurationProvider for EnvironmentVariablesConfigurationProvider {
-    fn get(&self, key: &str) -> Option<Value> {
-        self.data.get(&key.to_uppercase()).map(|t| t.1.clone())
-    }
 
-    fn load(&mut self) -> LoadResult {
+    fn read(&self) -> HashMap<String, (String, Value)> {
         let mut data = HashMap::new();
         let prefix = self.prefix.to_uppercase();
         let prefix_len = self.prefix.len();
 
         for (key, value) in vars() {
             if key.to_uppercase().starts_with(&prefix) {
-                let new_key = key[prefix_len..].to_string();
-                data.insert(new_key.to_uppercase().replace("__", ":"), (new_key, value.into()));
+                let new_key = key[prefix_len..].replace("__", ConfigurationPath::key_delimiter());
+                data.insert(new_key.to_uppercase(), (new_key, value.into()));
             }
         }
 
         data.shrink_to_fit();
-        self.data = data;
+        data
+    }
+}
+
+impl ConfigurationProvider for EnvironmentVariablesConfigurationProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.data.get(&key.to_uppercase()).map(|t| t.1.clone())
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.staged = None;
+        self.data = self.read();
+        Ok(())
+    }
+
+    fn stage(&mut self) -> LoadResult {
+        self.staged = Some(self.read());
         Ok(())
     }
 
+    fn commit(&mut self) {
+        if let Some(data) = self.staged.take() {
+            self.data = data;
+        }
+    }
+
+    fn discard(&mut self) {
+        self.staged = None;
+    }
+
     fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
         accumulate_child_keys(&self.data, earlier_keys, parent_path)
     }
@@ -1,6 +1,15 @@
 use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+#[cfg(feature = "file")]
+use {
+    crate::{util::accumulate_child_keys, ConfigurationProvider, LoadError, LoadResult, Value},
+    std::collections::HashMap,
+    std::fs,
+    std::sync::{Arc, RwLock},
+    tokens::{ChangeToken, FileChangeToken, SharedChangeToken, SingleChangeToken, Subscription},
+};
+
 /// Represents a file configuration source.
 #[derive(Clone)]
 pub struct FileSource {
@@ -157,6 +166,210 @@ impl From<&FileSourceBuilder> for FileSource {
     }
 }
 
+/// Defines the behavior of an object that parses the content of a configuration file.
+#[cfg(feature = "file")]
+#[cfg_attr(docsrs, doc(cfg(feature = "file")))]
+pub trait FileConfigurationParser {
+    /// Parses the content of a configuration file into configuration key/value pairs.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The content of the configuration file
+    /// * `path` - The path of the configuration file
+    ///
+    /// # Remarks
+    ///
+    /// The data key is normalized to uppercase. The value is a tuple where the
+    /// first item is the originally-cased key and the second item is value. An
+    /// error is the message describing why the content is invalid.
+    fn parse(
+        &self,
+        content: &[u8],
+        path: &Path,
+    ) -> Result<HashMap<String, (String, Value)>, String>;
+}
+
+// the data read from a file; none indicates the file does not exist
+#[cfg(feature = "file")]
+type FileData = Option<HashMap<String, (String, Value)>>;
+
+#[cfg(feature = "file")]
+struct InnerProvider<P> {
+    file: FileSource,
+    parser: P,
+    data: RwLock<HashMap<String, (String, Value)>>,
+    token: RwLock<SharedChangeToken<SingleChangeToken>>,
+}
+
+#[cfg(feature = "file")]
+impl<P: FileConfigurationParser> InnerProvider<P> {
+    fn new(file: FileSource, parser: P) -> Self {
+        Self {
+            file,
+            parser,
+            data: RwLock::new(HashMap::with_capacity(0)),
+            token: Default::default(),
+        }
+    }
+
+    // reads the file without changing the values that are provided
+    fn read(&self, reload: bool) -> Result<FileData, LoadError> {
+        if !self.file.path.is_file() {
+            if self.file.optional || reload {
+                return Ok(None);
+            } else {
+                return Err(LoadError::File {
+                    message: format!(
+                        "The configuration file '{}' was not found and is not optional.",
+                        self.file.path.display()
+                    ),
+                    path: self.file.path.clone(),
+                });
+            }
+        }
+
+        let content = fs::read(&self.file.path).map_err(|e| LoadError::File {
+            message: format!(
+                "The configuration file '{}' could not be read. {}",
+                self.file.path.display(),
+                e
+            ),
+            path: self.file.path.clone(),
+        })?;
+        let data = self
+            .parser
+            .parse(&content, &self.file.path)
+            .map_err(|message| LoadError::File {
+                message,
+                path: self.file.path.clone(),
+            })?;
+
+        Ok(Some(data))
+    }
+
+    fn replace(&self, data: FileData) {
+        if let Some(data) = data {
+            *self.data.write().unwrap() = data;
+
+            let previous = std::mem::take(&mut *self.token.write().unwrap());
+
+            previous.notify();
+        } else {
+            let mut data = self.data.write().unwrap();
+
+            if !data.is_empty() {
+                *data = HashMap::with_capacity(0);
+            }
+        }
+    }
+
+    fn load(&self, reload: bool) -> LoadResult {
+        let data = self.read(reload)?;
+        self.replace(data);
+        Ok(())
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        self.data
+            .read()
+            .unwrap()
+            .get(&key.to_uppercase())
+            .map(|t| t.1.clone())
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        Box::new(self.token.read().unwrap().clone())
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        let data = self.data.read().unwrap();
+        accumulate_child_keys(&data, earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for files.
+///
+/// # Remarks
+///
+/// The provider determines whether the file exists, reads it, and reloads it when it
+/// changes. Parsing the file content is deferred to a [`FileConfigurationParser`].
+#[cfg(feature = "file")]
+#[cfg_attr(docsrs, doc(cfg(feature = "file")))]
+pub struct FileConfigurationProvider<P> {
+    inner: Arc<InnerProvider<P>>,
+    staged: Option<FileData>,
+    _subscription: Option<Box<dyn Subscription>>,
+}
+
+#[cfg(feature = "file")]
+impl<P: FileConfigurationParser + Send + Sync + 'static> FileConfigurationProvider<P> {
+    /// Initializes a new file configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The [`FileSource`] information
+    /// * `parser` - The [`FileConfigurationParser`] used to parse the file content
+    pub fn new(file: FileSource, parser: P) -> Self {
+        let path = file.path.clone();
+        let inner = Arc::new(InnerProvider::new(file, parser));
+        let subscription: Option<Box<dyn Subscription>> = if inner.file.reload_on_change {
+            Some(Box::new(tokens::on_change(
+                move || FileChangeToken::new(path.clone()),
+                |state| {
+                    let provider = state.unwrap();
+                    std::thread::sleep(provider.file.reload_delay);
+                    provider.load(true).ok();
+                },
+                Some(inner.clone()),
+            )))
+        } else {
+            None
+        };
+
+        Self {
+            inner,
+            staged: None,
+            _subscription: subscription,
+        }
+    }
+}
+
+#[cfg(feature = "file")]
+impl<P: FileConfigurationParser> ConfigurationProvider for FileConfigurationProvider<P> {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.inner.get(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.inner.reload_token()
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.staged = None;
+        self.inner.load(false)
+    }
+
+    fn stage(&mut self) -> LoadResult {
+        self.staged = None;
+        self.staged = Some(self.inner.read(false)?);
+        Ok(())
+    }
+
+    fn commit(&mut self) {
+        if let Some(data) = self.staged.take() {
+            self.inner.replace(data);
+        }
+    }
+
+    fn discard(&mut self) {
+        self.staged = None;
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.inner.child_keys(earlier_keys, parent_path)
+    }
+}
+
 pub mod ext {
 
     use super::*;
@@ -1,102 +1,104 @@
 use crate::FileSource;
 use crate::{
-    util::accumulate_child_keys, ConfigurationBuilder, ConfigurationPath, ConfigurationProvider,
-    ConfigurationSource, LoadError, LoadResult, Value
+    ConfigurationBuilder, ConfigurationPath, ConfigurationProvider, ConfigurationSource,
+    FileConfigurationParser, FileConfigurationProvider, LoadResult, Value,
 };
 use configparser::ini::Ini;
-use std::collections::HashMap;
-use std::sync::{Arc, RwLock};
-use tokens::{ChangeToken, FileChangeToken, SharedChangeToken, SingleChangeToken, Subscription};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use tokens::ChangeToken;
 
-struct InnerProvider {
-    file: FileSource,
-    data: RwLock<HashMap<String, (String, Value)>>,
-    token: RwLock<SharedChangeToken<SingleChangeToken>>,
-}
+// the parser silently overwrites duplicate keys, but configuration keys are case-insensitive
+// and a repeated key is almost certainly a mistake so scan for them before parsing
+fn find_duplicate_key(content: &str, delimiters: &[char]) -> Option<String> {
+    let mut keys = HashSet::new();
+    let mut section = String::new();
 
-impl InnerProvider {
-    fn new(file: FileSource) -> Self {
-        Self {
-            file,
-            data: RwLock::new(HashMap::with_capacity(0)),
-            token: Default::default(),
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+            continue;
         }
-    }
 
-    fn get(&self, key: &str) -> Option<Value> {
-        self.data
-            .read()
-            .unwrap()
-            .get(&key.to_uppercase())
-            .map(|t| t.1.clone())
-    }
+        if line.starts_with('[') && line.ends_with(']') {
+            section = line[1..(line.len() - 1)].trim().to_owned();
+            continue;
+        }
 
-    fn reload_token(&self) -> Box<dyn ChangeToken> {
-        Box::new(self.token.read().unwrap().clone())
-    }
-
-    fn load(&self, reload: bool) -> LoadResult {
-        if !self.file.path.is_file() {
-            if self.file.optional || reload {
-                let mut data = self.data.write().unwrap();
-                if !data.is_empty() {
-                    *data = HashMap::with_capacity(0);
-                }
-
-                return Ok(());
-            } else {
-                return Err(LoadError::File {
-                    message: format!(
-                        "The configuration file '{}' was not found and is not optional.",
-                        self.file.path.display()
-                    ),
-                    path: self.file.path.clone(),
-                });
-            }
+        let key = line.find(delimiters).map_or(line, |i| &line[..i]).trim();
+        let path = ConfigurationPath::combine(&[&section, key]).to_uppercase();
+
+        if !keys.insert(path) {
+            return Some(format!(
+                "A duplicate key '{}' was found in section '{}'. (Line: {})",
+                key,
+                section,
+                index + 1
+            ));
         }
+    }
+
+    None
+}
+
+struct IniParser {
+    nested_keys: bool,
+}
+
+impl FileConfigurationParser for IniParser {
+    fn parse(
+        &self,
+        content: &[u8],
+        path: &Path,
+    ) -> Result<HashMap<String, (String, Value)>, String> {
+        let content = String::from_utf8(content.to_vec()).map_err(|e| {
+            format!(
+                "The configuration file '{}' could not be read. {}",
+                path.display(),
+                e
+            )
+        })?;
 
         let mut ini = Ini::new_cs();
-        let data = if let Ok(sections) = ini.load(&self.file.path) {
-            let capacity = sections.iter().map(|p| p.1.len()).sum();
-            let mut map = HashMap::with_capacity(capacity);
-
-            for (section, pairs) in sections {
-                for (key, value) in pairs {
-                    let mut new_key = section.to_owned();
-                    let new_value = value.unwrap_or_default();
-
-                    new_key.push_str(ConfigurationPath::key_delimiter());
-                    new_key.push_str(&key);
-                    map.insert(new_key.to_uppercase(), (new_key, new_value.into()));
-                }
-            }
+        let mut defaults = ini.defaults();
 
-            map
-        } else {
-            HashMap::with_capacity(0)
-        };
+        // ':' is the configuration key delimiter so it can only be part of a nested key when '='
+        // is the only separator
+        if self.nested_keys {
+            defaults.delimiters = vec!['='];
+        }
 
-        *self.data.write().unwrap() = data;
+        if let Some(message) = find_duplicate_key(&content, &defaults.delimiters) {
+            return Err(message);
+        }
 
-        let previous = std::mem::replace(
-            &mut *self.token.write().unwrap(),
-            SharedChangeToken::default(),
-        );
+        ini.load_defaults(defaults);
 
-        previous.notify();
-        Ok(())
-    }
+        let sections = ini
+            .read(content)
+            .map_err(|e| format!("Could not parse the INI file '{}'. {}", path.display(), e))?;
+        let capacity = sections.iter().map(|p| p.1.len()).sum();
+        let mut data = HashMap::with_capacity(capacity);
 
-    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
-        let data = self.data.read().unwrap();
-        accumulate_child_keys(&data, earlier_keys, parent_path)
+        for (section, pairs) in sections {
+            for (key, value) in pairs {
+                let mut new_key = section.to_owned();
+                let new_value = value.unwrap_or_default();
+
+                new_key.push_str(ConfigurationPath::key_delimiter());
+                new_key.push_str(&key);
+                data.insert(new_key.to_uppercase(), (new_key, new_value.into()));
+            }
+        }
+
+        Ok(data)
     }
 }
 
 /// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for `*.ini` files.
 pub struct IniConfigurationProvider {
-    inner: Arc<InnerProvider>,
-    _subscription: Option<Box<dyn Subscription>>,
+    inner: FileConfigurationProvider<IniParser>,
 }
 
 impl IniConfigurationProvider {
@@ -106,25 +108,19 @@ impl IniConfigurationProvider {
     ///
     /// * `file` - The `*.ini` [`FileSource`](crate::FileSource) information
     pub fn new(file: FileSource) -> Self {
-        let path = file.path.clone();
-        let inner = Arc::new(InnerProvider::new(file));
-        let subscription: Option<Box<dyn Subscription>> = if inner.file.reload_on_change {
-            Some(Box::new(tokens::on_change(
-                move || FileChangeToken::new(path.clone()),
-                |state| {
-                    let provider = state.unwrap();
-                    std::thread::sleep(provider.file.reload_delay);
-                    provider.load(true).ok();
-                },
-                Some(inner.clone()),
-            )))
-        } else {
-            None
-        };
+        Self::with_nested_keys(file, false)
+    }
 
+    /// Initializes a new `*.ini` file configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The `*.ini` [`FileSource`](crate::FileSource) information
+    /// * `nested_keys` - Indicates whether only `=` separates a key from its value so that a `:` in
+    ///   a key is the configuration key delimiter
+    pub fn with_nested_keys(file: FileSource, nested_keys: bool) -> Self {
         Self {
-            inner,
-            _subscription: subscription,
+            inner: FileConfigurationProvider::new(file, IniParser { nested_keys }),
         }
     }
 }
@@ -139,7 +135,19 @@ impl ConfigurationProvider for IniConfigurationProvider {
     }
 
     fn load(&mut self) -> LoadResult {
-        self.inner.load(false)
+        self.inner.load()
+    }
+
+    fn stage(&mut self) -> LoadResult {
+        self.inner.stage()
+    }
+
+    fn commit(&mut self) {
+        self.inner.commit()
+    }
+
+    fn discard(&mut self) {
+        self.inner.discard()
     }
 
     fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
@@ -150,6 +158,7 @@ impl ConfigurationProvider for IniConfigurationProvider {
 /// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for `*.ini` files.
 pub struct IniConfigurationSource {
     file: FileSource,
+    nested_keys: bool,
 }
 
 impl IniConfigurationSource {
@@ -159,13 +168,30 @@ impl IniConfigurationSource {
     ///
     /// * `file` - The `*.ini` [`FileSource`](crate::FileSource) information
     pub fn new(file: FileSource) -> Self {
-        Self { file }
+        Self {
+            file,
+            nested_keys: false,
+        }
+    }
+
+    /// Sets whether only `=` separates a key from its value so that a `:` in a key is the
+    /// configuration key delimiter.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Indicates whether keys are nested; the default value is `false`
+    pub fn nested_keys(mut self, enabled: bool) -> Self {
+        self.nested_keys = enabled;
+        self
     }
 }
 
 impl ConfigurationSource for IniConfigurationSource {
     fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
-        Box::new(IniConfigurationProvider::new(self.file.clone()))
+        Box::new(IniConfigurationProvider::with_nested_keys(
+            self.file.clone(),
+            self.nested_keys,
+        ))
     }
 }
 
@@ -181,6 +207,19 @@ pub mod ext {
         ///
         /// * `file` - The `*.ini` [`FileSource`](crate::FileSource) information
         fn add_ini_file<T: Into<FileSource>>(&mut self, file: T) -> &mut Self;
+
+        /// Adds an `*.ini` file as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `file` - The `*.ini` [`FileSource`](crate::FileSource) information
+        /// * `nested_keys` - Indicates whether only `=` separates a key from its value so that a `:`
+        ///   in a key is the configuration key delimiter
+        fn add_ini_file_with_nested_keys<T: Into<FileSource>>(
+            &mut self,
+            file: T,
+            nested_keys: bool,
+        ) -> &mut Self;
     }
 
     impl IniConfigurationExtensions for dyn ConfigurationBuilder {
@@ -188,6 +227,16 @@ pub mod ext {
             self.add(Box::new(IniConfigurationSource::new(file.into())));
             self
         }
+
+        fn add_ini_file_with_nested_keys<T: Into<FileSource>>(
+            &mut self,
+            file: T,
+            nested_keys: bool,
+        ) -> &mut Self {
+            let source = IniConfigurationSource::new(file.into()).nested_keys(nested_keys);
+            self.add(Box::new(source));
+            self
+        }
     }
 
     impl<T: ConfigurationBuilder> IniConfigurationExtensions for T {
@@ -195,5 +244,15 @@ pub mod ext {
             self.add(Box::new(IniConfigurationSource::new(file.into())));
             self
         }
+
+        fn add_ini_file_with_nested_keys<F: Into<FileSource>>(
+            &mut self,
+            file: F,
+            nested_keys: bool,
+        ) -> &mut Self {
+            let source = IniConfigurationSource::new(file.into()).nested_keys(nested_keys);
+            self.add(Box::new(source));
+            self
+        }
     }
 }
@@ -1,12 +1,12 @@
 use crate::{
-    util::*, ConfigurationBuilder, ConfigurationPath, ConfigurationProvider, ConfigurationSource,
+    ext::ConfigurationExtensions, util::*, Configuration, ConfigurationBuilder, ConfigurationPath,
+    ConfigurationProvider, ConfigurationSource, FileConfigurationParser, FileConfigurationProvider,
     FileSource, LoadError, LoadResult, Value,
 };
 use serde_json::{map::Map, Value as JsonValue};
 use std::collections::HashMap;
-use std::fs;
-use std::sync::{Arc, RwLock};
-use tokens::{ChangeToken, FileChangeToken, SharedChangeToken, SingleChangeToken, Subscription};
+use std::path::Path;
+use tokens::ChangeToken;
 
 #[derive(Default)]
 struct JsonVisitor {
@@ -74,99 +74,50 @@ impl JsonVisitor {
     }
 }
 
-struct InnerProvider {
-    file: FileSource,
-    data: RwLock<HashMap<String, (String, Value)>>,
-    token: RwLock<SharedChangeToken<SingleChangeToken>>,
-}
-
-impl InnerProvider {
-    fn new(file: FileSource) -> Self {
-        Self {
-            file,
-            data: RwLock::new(HashMap::with_capacity(0)),
-            token: Default::default(),
-        }
-    }
-
-    fn load(&self, reload: bool) -> LoadResult {
-        if !self.file.path.is_file() {
-            if self.file.optional || reload {
-                let mut data = self.data.write().unwrap();
-                if !data.is_empty() {
-                    *data = HashMap::with_capacity(0);
-                }
-
-                return Ok(());
-            } else {
-                return Err(LoadError::File {
-                    message: format!(
-                        "The configuration file '{}' was not found and is not optional.",
-                        self.file.path.display()
-                    ),
-                    path: self.file.path.clone(),
-                });
-            }
-        }
+struct JsonParser;
 
+impl FileConfigurationParser for JsonParser {
+    fn parse(
+        &self,
+        content: &[u8],
+        path: &Path,
+    ) -> Result<HashMap<String, (String, Value)>, String> {
         // REF: https://docs.serde.rs/serde_json/de/fn.from_reader.html
-        let content = fs::read(&self.file.path).unwrap();
-        let json: JsonValue = serde_json::from_slice(&content).unwrap();
-
-        if let Some(root) = json.as_object() {
-            let visitor = JsonVisitor::default();
-            let data = visitor.visit(root);
-            *self.data.write().unwrap() = data;
-        } else if reload {
-            *self.data.write().unwrap() = HashMap::with_capacity(0);
-        } else {
-            return Err(LoadError::File {
-                message: format!(
-                    "Top-level JSON element must be an object. Instead, '{}' was found.",
-                    match json {
-                        JsonValue::Array(_) => "array",
-                        JsonValue::Bool(_) => "Boolean",
-                        JsonValue::Null => "null",
-                        JsonValue::Number(_) => "number",
-                        JsonValue::String(_) => "string",
-                        _ => unreachable!(),
-                    }
-                ),
-                path: self.file.path.clone(),
-            });
-        }
-
-        let previous = std::mem::replace(
-            &mut *self.token.write().unwrap(),
-            SharedChangeToken::default(),
-        );
-
-        previous.notify();
-        Ok(())
-    }
-
-    fn get(&self, key: &str) -> Option<Value> {
-        self.data
-            .read()
-            .unwrap()
-            .get(&key.to_uppercase())
-            .map(|t| t.1.clone())
-    }
-
-    fn reload_token(&self) -> Box<dyn ChangeToken> {
-        Box::new(self.token.read().unwrap().clone())
+        let json: JsonValue = serde_json::from_slice(content).map_err(|e| {
+            format!(
+                "Could not parse the JSON file '{}'. Error on line number '{}': {}",
+                path.display(),
+                e.line(),
+                e
+            )
+        })?;
+
+        flatten(json)
     }
+}
 
-    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
-        let data = self.data.read().unwrap();
-        accumulate_child_keys(&data, earlier_keys, parent_path)
+fn flatten(json: JsonValue) -> Result<HashMap<String, (String, Value)>, String> {
+    if let Some(root) = json.as_object() {
+        let visitor = JsonVisitor::default();
+        Ok(visitor.visit(root))
+    } else {
+        Err(format!(
+            "Top-level JSON element must be an object. Instead, '{}' was found.",
+            match json {
+                JsonValue::Array(_) => "array",
+                JsonValue::Bool(_) => "Boolean",
+                JsonValue::Null => "null",
+                JsonValue::Number(_) => "number",
+                JsonValue::String(_) => "string",
+                _ => unreachable!(),
+            }
+        ))
     }
 }
 
 /// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for `*.json` files.
 pub struct JsonConfigurationProvider {
-    inner: Arc<InnerProvider>,
-    _subscription: Option<Box<dyn Subscription>>,
+    inner: FileConfigurationProvider<JsonParser>,
 }
 
 impl JsonConfigurationProvider {
@@ -176,25 +127,8 @@ impl JsonConfigurationProvider {
     ///
     /// * `file` - The `*.json` [`FileSource`](crate::FileSource) information
     pub fn new(file: FileSource) -> Self {
-        let path = file.path.clone();
-        let inner = Arc::new(InnerProvider::new(file));
-        let subscription: Option<Box<dyn Subscription>> = if inner.file.reload_on_change {
-            Some(Box::new(tokens::on_change(
-                move || FileChangeToken::new(path.clone()),
-                |state| {
-                    let provider = state.unwrap();
-                    std::thread::sleep(provider.file.reload_delay);
-                    provider.load(true).ok();
-                },
-                Some(inner.clone())
-            )))
-        } else {
-            None
-        };
-
         Self {
-            inner,
-            _subscription: subscription,
+            inner: FileConfigurationProvider::new(file, JsonParser),
         }
     }
 }
@@ -209,7 +143,19 @@ impl ConfigurationProvider for JsonConfigurationProvider {
     }
 
     fn load(&mut self) -> LoadResult {
-        self.inner.load(false)
+        self.inner.load()
+    }
+
+    fn stage(&mut self) -> LoadResult {
+        self.inner.stage()
+    }
+
+    fn commit(&mut self) {
+        self.inner.commit()
+    }
+
+    fn discard(&mut self) {
+        self.inner.discard()
     }
 
     fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
@@ -239,6 +185,213 @@ impl ConfigurationSource for JsonConfigurationSource {
     }
 }
 
+#[derive(Clone)]
+enum JsonBlob {
+    Text(String),
+    Variable(String),
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for a JSON document
+/// held in a string or an environment variable.
+pub struct JsonBlobConfigurationProvider {
+    blob: JsonBlob,
+    data: HashMap<String, (String, Value)>,
+    staged: Option<HashMap<String, (String, Value)>>,
+}
+
+impl JsonBlobConfigurationProvider {
+    /// Initializes a new JSON configuration provider for a JSON document.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The JSON document to provide configuration values from
+    pub fn new<S: Into<String>>(json: S) -> Self {
+        Self {
+            blob: JsonBlob::Text(json.into()),
+            data: HashMap::new(),
+            staged: None,
+        }
+    }
+
+    /// Initializes a new JSON configuration provider for a JSON document held in an environment variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the environment variable that contains the JSON document
+    ///
+    /// # Remarks
+    ///
+    /// The environment variable is read each time the provider is loaded. If the environment
+    /// variable is not set, the provider does not have any configuration values.
+    pub fn from_env_var<S: Into<String>>(name: S) -> Self {
+        Self {
+            blob: JsonBlob::Variable(name.into()),
+            data: HashMap::new(),
+            staged: None,
+        }
+    }
+}
+
+impl JsonBlobConfigurationProvider {
+    fn read(&self) -> Result<HashMap<String, (String, Value)>, LoadError> {
+        let (json, origin) = match &self.blob {
+            JsonBlob::Text(json) => (json.clone(), "JSON document".to_owned()),
+            JsonBlob::Variable(name) => match std::env::var(name) {
+                Ok(json) => (json, format!("environment variable '{}'", name)),
+                Err(_) => return Ok(HashMap::new()),
+            },
+        };
+        let json: JsonValue = serde_json::from_str(&json).map_err(|e| {
+            LoadError::Generic(format!(
+                "Could not parse the {}. Error on line number '{}': {}",
+                origin,
+                e.line(),
+                e
+            ))
+        })?;
+
+        flatten(json).map_err(LoadError::Generic)
+    }
+}
+
+impl ConfigurationProvider for JsonBlobConfigurationProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.data.get(&key.to_uppercase()).map(|t| t.1.clone())
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.staged = None;
+        self.data = self.read()?;
+        Ok(())
+    }
+
+    fn stage(&mut self) -> LoadResult {
+        self.staged = None;
+        self.staged = Some(self.read()?);
+        Ok(())
+    }
+
+    fn commit(&mut self) {
+        if let Some(data) = self.staged.take() {
+            self.data = data;
+        }
+    }
+
+    fn discard(&mut self) {
+        self.staged = None;
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        accumulate_child_keys(&self.data, earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for a JSON document
+/// held in a string or an environment variable.
+pub struct JsonBlobConfigurationSource {
+    blob: JsonBlob,
+}
+
+impl JsonBlobConfigurationSource {
+    /// Initializes a new JSON configuration source for a JSON document.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The JSON document to provide configuration values from
+    pub fn new<S: Into<String>>(json: S) -> Self {
+        Self {
+            blob: JsonBlob::Text(json.into()),
+        }
+    }
+
+    /// Initializes a new JSON configuration source for a JSON document held in an environment variable.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the environment variable that contains the JSON document
+    pub fn from_env_var<S: Into<String>>(name: S) -> Self {
+        Self {
+            blob: JsonBlob::Variable(name.into()),
+        }
+    }
+}
+
+impl ConfigurationSource for JsonBlobConfigurationSource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(JsonBlobConfigurationProvider {
+            blob: self.blob.clone(),
+            data: HashMap::new(),
+            staged: None,
+        })
+    }
+}
+
+fn insert_json(object: &mut Map<String, JsonValue>, segments: &[&str], value: String) {
+    let (segment, rest) = segments.split_first().unwrap();
+
+    if rest.is_empty() {
+        // a key with children cannot also hold a value; the children win
+        if !object.contains_key(*segment) {
+            object.insert(segment.to_string(), JsonValue::String(value));
+        }
+    } else {
+        let child = object
+            .entry(segment.to_string())
+            .or_insert_with(|| JsonValue::Object(Map::new()));
+
+        if !child.is_object() {
+            *child = JsonValue::Object(Map::new());
+        }
+
+        insert_json(child.as_object_mut().unwrap(), rest, value);
+    }
+}
+
+fn rebuild_arrays(value: JsonValue) -> JsonValue {
+    if let JsonValue::Object(object) = value {
+        let mut ordinals: Vec<_> = object
+            .keys()
+            .filter_map(|key| key.parse::<usize>().ok())
+            .collect();
+
+        ordinals.sort_unstable();
+
+        let is_array = !object.is_empty()
+            && ordinals.len() == object.len()
+            && ordinals.iter().enumerate().all(|(i, ordinal)| i == *ordinal);
+
+        if is_array {
+            let mut items: Vec<_> = object
+                .into_iter()
+                .map(|(key, item)| (key.parse::<usize>().unwrap(), rebuild_arrays(item)))
+                .collect();
+
+            items.sort_by_key(|(ordinal, _)| *ordinal);
+            JsonValue::Array(items.into_iter().map(|(_, item)| item).collect())
+        } else {
+            JsonValue::Object(
+                object
+                    .into_iter()
+                    .map(|(key, item)| (key, rebuild_arrays(item)))
+                    .collect(),
+            )
+        }
+    } else {
+        value
+    }
+}
+
+fn to_json(configuration: &(dyn Configuration + 'static)) -> JsonValue {
+    let mut root = Map::new();
+
+    for (key, value) in configuration.to_sorted_pairs() {
+        let segments: Vec<_> = key.split(ConfigurationPath::key_delimiter()).collect();
+        insert_json(&mut root, &segments, value);
+    }
+
+    rebuild_arrays(JsonValue::Object(root))
+}
+
 pub mod ext {
 
     use super::*;
@@ -251,6 +404,20 @@ pub mod ext {
         ///
         /// * `file` - The `*.json` [`FileSource`](crate::FileSource) information
         fn add_json_file<T: Into<FileSource>>(&mut self, file: T) -> &mut Self;
+
+        /// Adds a JSON document as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `json` - The JSON document to provide configuration values from
+        fn add_json_str<S: Into<String>>(&mut self, json: S) -> &mut Self;
+
+        /// Adds a JSON document held in an environment variable as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `name` - The name of the environment variable that contains the JSON document
+        fn add_json_env_var<S: Into<String>>(&mut self, name: S) -> &mut Self;
     }
 
     impl JsonConfigurationExtensions for dyn ConfigurationBuilder {
@@ -258,6 +425,16 @@ pub mod ext {
             self.add(Box::new(JsonConfigurationSource::new(file.into())));
             self
         }
+
+        fn add_json_str<S: Into<String>>(&mut self, json: S) -> &mut Self {
+            self.add(Box::new(JsonBlobConfigurationSource::new(json)));
+            self
+        }
+
+        fn add_json_env_var<S: Into<String>>(&mut self, name: S) -> &mut Self {
+            self.add(Box::new(JsonBlobConfigurationSource::from_env_var(name)));
+            self
+        }
     }
 
     impl<T: ConfigurationBuilder> JsonConfigurationExtensions for T {
@@ -265,5 +442,38 @@ pub mod ext {
             self.add(Box::new(JsonConfigurationSource::new(file.into())));
             self
         }
+
+        fn add_json_str<S: Into<String>>(&mut self, json: S) -> &mut Self {
+            self.add(Box::new(JsonBlobConfigurationSource::new(json)));
+            self
+        }
+
+        fn add_json_env_var<S: Into<String>>(&mut self, name: S) -> &mut Self {
+            self.add(Box::new(JsonBlobConfigurationSource::from_env_var(name)));
+            self
+        }
+    }
+
+    /// Defines extension methods for exporting a [`Configuration`](crate::Configuration) as JSON.
+    pub trait JsonExportExtensions {
+        /// Gets the configuration as structured JSON.
+        ///
+        /// # Remarks
+        ///
+        /// Keys are split into nested objects by the key delimiter. Objects whose keys are the
+        /// consecutive, zero-based ordinals `0..n` are rebuilt as arrays. All values are strings.
+        fn to_json(&self) -> JsonValue;
+    }
+
+    impl JsonExportExtensions for dyn Configuration {
+        fn to_json(&self) -> JsonValue {
+            to_json(self)
+        }
+    }
+
+    impl<C: AsRef<dyn Configuration>> JsonExportExtensions for C {
+        fn to_json(&self) -> JsonValue {
+            to_json(self.as_ref())
+        }
     }
 }
@@ -0,0 +1,165 @@
+use crate::{
+    util::cmp_keys, ConfigurationBuilder, ConfigurationProvider, ConfigurationSource, LoadResult,
+    Value,
+};
+use std::any::type_name;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tokens::{ChangeToken, NeverChangeToken};
+
+/// Defines the behavior of a configuration store whose values are fetched on demand.
+pub trait LazyConfigurationStore {
+    /// Gets the name of the store.
+    fn name(&self) -> &str {
+        type_name::<Self>()
+    }
+
+    /// Fetches the configuration value with the specified key from the store.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to fetch
+    fn fetch(&self, key: &str) -> Option<String>;
+
+    /// Gets the immediate descendent configuration keys of the specified parent path.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent_path` - The optional parent path to evaluate
+    fn child_keys(&self, parent_path: Option<&str>) -> Vec<String>;
+
+    /// Returns a [`ChangeToken`](tokens::ChangeToken) that indicates when the values in the store have changed.
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        Box::new(NeverChangeToken::new())
+    }
+
+    /// Prepares the store for use, such as establishing a connection.
+    ///
+    /// # Remarks
+    ///
+    /// This method should do the minimal amount of work necessary. Values are fetched as they are requested.
+    fn load(&mut self) -> LoadResult {
+        Ok(())
+    }
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) that fetches
+/// values from a [`LazyConfigurationStore`] on first access.
+///
+/// # Remarks
+///
+/// Fetched values, including values that do not exist, are cached until the provider is
+/// reloaded or the [reload token](LazyConfigurationStore::reload_token) of the store changes.
+pub struct LazyConfigurationProvider<S: LazyConfigurationStore> {
+    store: S,
+    cache: Mutex<HashMap<String, Option<Value>>>,
+    token: Mutex<Box<dyn ChangeToken>>,
+}
+
+impl<S: LazyConfigurationStore> LazyConfigurationProvider<S> {
+    /// Initializes a new lazy configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `store` - The [`LazyConfigurationStore`] values are fetched from
+    pub fn new(store: S) -> Self {
+        let token = Mutex::new(store.reload_token());
+
+        Self {
+            store,
+            cache: Default::default(),
+            token,
+        }
+    }
+
+    /// Clears the cached values so that they are fetched again on next access.
+    pub fn invalidate(&self) {
+        self.cache.lock().unwrap().clear();
+        *self.token.lock().unwrap() = self.store.reload_token();
+    }
+}
+
+impl<S: LazyConfigurationStore> ConfigurationProvider for LazyConfigurationProvider<S> {
+    fn name(&self) -> &str {
+        self.store.name()
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        if self.token.lock().unwrap().changed() {
+            self.invalidate();
+        }
+
+        self.cache
+            .lock()
+            .unwrap()
+            .entry(key.to_uppercase())
+            .or_insert_with(|| self.store.fetch(key).map(Value::from))
+            .clone()
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.store.reload_token()
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.store.load()?;
+        self.invalidate();
+        Ok(())
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        earlier_keys.extend(self.store.child_keys(parent_path));
+        earlier_keys.sort_by(|k1, k2| cmp_keys(k1, k2));
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for a [`LazyConfigurationStore`].
+pub struct LazyConfigurationSource<S: LazyConfigurationStore + Clone> {
+    store: S,
+}
+
+impl<S: LazyConfigurationStore + Clone> LazyConfigurationSource<S> {
+    /// Initializes a new lazy configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `store` - The [`LazyConfigurationStore`] values are fetched from
+    pub fn new(store: S) -> Self {
+        Self { store }
+    }
+}
+
+impl<S: LazyConfigurationStore + Clone + 'static> ConfigurationSource for LazyConfigurationSource<S> {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(LazyConfigurationProvider::new(self.store.clone()))
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait LazyConfigurationBuilderExtensions {
+        /// Adds a configuration source whose values are fetched on first access.
+        ///
+        /// # Arguments
+        ///
+        /// * `store` - The [`LazyConfigurationStore`] values are fetched from
+        fn add_lazy<S: LazyConfigurationStore + Clone + 'static>(&mut self, store: S) -> &mut Self;
+    }
+
+    impl LazyConfigurationBuilderExtensions for dyn ConfigurationBuilder {
+        fn add_lazy<S: LazyConfigurationStore + Clone + 'static>(&mut self, store: S) -> &mut Self {
+            self.add(Box::new(LazyConfigurationSource::new(store)));
+            self
+        }
+    }
+
+    impl<T: ConfigurationBuilder> LazyConfigurationBuilderExtensions for T {
+        fn add_lazy<S: LazyConfigurationStore + Clone + 'static>(&mut self, store: S) -> &mut Self {
+            self.add(Box::new(LazyConfigurationSource::new(store)));
+            self
+        }
+    }
+}
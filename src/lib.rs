@@ -30,12 +30,21 @@ mod default;
 #[cfg(feature = "mem")]
 mod memory;
 
+#[cfg(feature = "prefixed")]
+mod prefixed;
+
+#[cfg(feature = "lazy")]
+mod lazy;
+
 #[cfg(feature = "env")]
 mod env;
 
 #[cfg(feature = "ini")]
 mod ini;
 
+#[cfg(feature = "dotenv")]
+mod dotenv;
+
 #[cfg(feature = "json")]
 mod json;
 
@@ -45,6 +54,12 @@ mod cmd;
 #[cfg(feature = "xml")]
 mod xml;
 
+#[cfg(feature = "yaml")]
+mod yaml;
+
+#[cfg(feature = "toml")]
+mod toml;
+
 #[cfg(feature = "binder")]
 mod binder;
 
@@ -75,7 +90,18 @@ pub use default::*;
 
 #[cfg(feature = "mem")]
 #[cfg_attr(docsrs, doc(cfg(feature = "mem")))]
-pub use memory::{MemoryConfigurationProvider, MemoryConfigurationSource};
+pub use memory::{
+    MemoryConfigurationProvider, MemoryConfigurationSource, MutableMemoryConfigurationProvider,
+    MutableMemoryConfigurationSource,
+};
+
+#[cfg(feature = "prefixed")]
+#[cfg_attr(docsrs, doc(cfg(feature = "prefixed")))]
+pub use prefixed::{PrefixedConfigurationProvider, PrefixedConfigurationSource};
+
+#[cfg(feature = "lazy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "lazy")))]
+pub use lazy::{LazyConfigurationProvider, LazyConfigurationSource, LazyConfigurationStore};
 
 #[cfg(feature = "env")]
 #[cfg_attr(docsrs, doc(cfg(feature = "env")))]
@@ -85,9 +111,16 @@ pub use env::{EnvironmentVariablesConfigurationProvider, EnvironmentVariablesCon
 #[cfg_attr(docsrs, doc(cfg(feature = "ini")))]
 pub use ini::{IniConfigurationProvider, IniConfigurationSource};
 
+#[cfg(feature = "dotenv")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dotenv")))]
+pub use dotenv::{DotEnvConfigurationProvider, DotEnvConfigurationSource};
+
 #[cfg(feature = "json")]
 #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
-pub use json::{JsonConfigurationProvider, JsonConfigurationSource};
+pub use json::{
+    JsonBlobConfigurationProvider, JsonBlobConfigurationSource, JsonConfigurationProvider,
+    JsonConfigurationSource,
+};
 
 #[cfg(feature = "cmd")]
 #[cfg_attr(docsrs, doc(cfg(feature = "cmd")))]
@@ -97,6 +130,14 @@ pub use cmd::{CommandLineConfigurationProvider, CommandLineConfigurationSource};
 #[cfg_attr(docsrs, doc(cfg(feature = "xml")))]
 pub use xml::{XmlConfigurationProvider, XmlConfigurationSource};
 
+#[cfg(feature = "yaml")]
+#[cfg_attr(docsrs, doc(cfg(feature = "yaml")))]
+pub use yaml::{YamlConfigurationProvider, YamlConfigurationSource};
+
+#[cfg(feature = "toml")]
+#[cfg_attr(docsrs, doc(cfg(feature = "toml")))]
+pub use toml::{TomlConfigurationProvider, TomlConfigurationSource};
+
 /// Contains configuration extension methods.
 pub mod ext {
 
@@ -114,6 +155,10 @@ pub mod ext {
     #[cfg_attr(docsrs, doc(cfg(feature = "ini")))]
     pub use ini::ext::*;
 
+    #[cfg(feature = "dotenv")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "dotenv")))]
+    pub use dotenv::ext::*;
+
     #[cfg(feature = "json")]
     #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
     pub use json::ext::*;
@@ -126,10 +171,26 @@ pub mod ext {
     #[cfg_attr(docsrs, doc(cfg(feature = "cmd")))]
     pub use cmd::ext::*;
 
+    #[cfg(feature = "prefixed")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "prefixed")))]
+    pub use prefixed::ext::*;
+
+    #[cfg(feature = "lazy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "lazy")))]
+    pub use lazy::ext::*;
+
     #[cfg(feature = "xml")]
     #[cfg_attr(docsrs, doc(cfg(feature = "xml")))]
     pub use super::xml::ext::*;
 
+    #[cfg(feature = "yaml")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "yaml")))]
+    pub use super::yaml::ext::*;
+
+    #[cfg(feature = "toml")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "toml")))]
+    pub use super::toml::ext::*;
+
     #[cfg(feature = "binder")]
     #[cfg_attr(docsrs, doc(cfg(feature = "binder")))]
     pub use binder::*;
@@ -138,6 +199,7 @@ pub mod ext {
     #[cfg_attr(docsrs, doc(cfg(feature = "binder")))]
     pub use de::*;
 
+    pub use configuration::ext::*;
     pub use section::ext::*;
     pub use file::ext::*;
 }
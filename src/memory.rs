@@ -1,7 +1,35 @@
 use crate::{
     util::accumulate_child_keys, ConfigurationBuilder, ConfigurationProvider, ConfigurationSource, Value,
 };
+use cfg_if::cfg_if;
 use std::collections::HashMap;
+use tokens::{ChangeToken, SharedChangeToken, SingleChangeToken};
+
+cfg_if! {
+    if #[cfg(feature = "async")] {
+        type Pc<T> = std::sync::Arc<T>;
+        type Mut<T> = std::sync::RwLock<T>;
+
+        fn read<T>(value: &Mut<T>) -> std::sync::RwLockReadGuard<'_, T> {
+            value.read().unwrap()
+        }
+
+        fn write<T>(value: &Mut<T>) -> std::sync::RwLockWriteGuard<'_, T> {
+            value.write().unwrap()
+        }
+    } else {
+        type Pc<T> = std::rc::Rc<T>;
+        type Mut<T> = std::cell::RefCell<T>;
+
+        fn read<T>(value: &Mut<T>) -> std::cell::Ref<'_, T> {
+            value.borrow()
+        }
+
+        fn write<T>(value: &Mut<T>) -> std::cell::RefMut<'_, T> {
+            value.borrow_mut()
+        }
+    }
+}
 
 /// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) that
 /// provides in-memory configuration values.
@@ -71,6 +99,133 @@ impl ConfigurationSource for MemoryConfigurationSource {
     }
 }
 
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) that
+/// provides in-memory configuration values which can be changed at runtime.
+///
+/// # Remarks
+///
+/// Clones share the same data so a clone can be kept as a handle to change the
+/// values after the provider has been added to a configuration.
+///
+/// Without the **async** feature, the data is shared through `Rc<RefCell<_>>`
+/// because [`Value`](crate::Value) is an `Rc<String>`. The provider is then
+/// neither `Send` nor `Sync` and [`set`](Self::set) or [`set_many`](Self::set_many)
+/// can only be called from the thread that owns the configuration. With the
+/// **async** feature enabled, the data is shared through `Arc<RwLock<_>>` and the
+/// values can be changed from any thread.
+#[derive(Clone, Default)]
+pub struct MutableMemoryConfigurationProvider {
+    data: Pc<Mut<HashMap<String, (String, Value)>>>,
+    token: Pc<Mut<SharedChangeToken<SingleChangeToken>>>,
+}
+
+impl MutableMemoryConfigurationProvider {
+    /// Initializes a new, mutable in-memory configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The in-memory data associated with the provider
+    ///
+    /// # Remarks
+    ///
+    /// The data key is normalized to uppercase. The value is a tuple where the
+    /// first item is the originally-cased key and the second item is value.
+    pub fn new(data: HashMap<String, (String, Value)>) -> Self {
+        Self {
+            data: Pc::new(Mut::new(data)),
+            token: Default::default(),
+        }
+    }
+
+    /// Sets a configuration value and notifies listeners of the change.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key of the value to set
+    /// * `value` - The value to set
+    ///
+    /// # Remarks
+    ///
+    /// Calls from other threads require the **async** feature.
+    pub fn set<S: AsRef<str>>(&self, key: S, value: S) {
+        self.set_many(&[(key, value)])
+    }
+
+    /// Sets multiple configuration values and notifies listeners of the change once.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - The key/value pairs to set
+    ///
+    /// # Remarks
+    ///
+    /// Calls from other threads require the **async** feature.
+    pub fn set_many<S: AsRef<str>>(&self, data: &[(S, S)]) {
+        {
+            let mut current = write(&self.data);
+
+            for (key, value) in data {
+                let key = key.as_ref();
+                current.insert(
+                    key.to_uppercase(),
+                    (key.to_owned(), value.as_ref().to_owned().into()),
+                );
+            }
+        }
+
+        let previous = std::mem::take(&mut *write(&self.token));
+        previous.notify();
+    }
+}
+
+impl ConfigurationProvider for MutableMemoryConfigurationProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        read(&self.data).get(&key.to_uppercase()).map(|t| t.1.clone())
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        Box::new(read(&self.token).clone())
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        accumulate_child_keys(&read(&self.data), earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for in-memory data
+/// which can be changed at runtime.
+pub struct MutableMemoryConfigurationSource {
+    provider: MutableMemoryConfigurationProvider,
+}
+
+impl MutableMemoryConfigurationSource {
+    /// Initializes a new, mutable in-memory configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The [`MutableMemoryConfigurationProvider`] shared by the source
+    pub fn new(provider: MutableMemoryConfigurationProvider) -> Self {
+        Self { provider }
+    }
+}
+
+impl ConfigurationSource for MutableMemoryConfigurationSource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(self.provider.clone())
+    }
+}
+
+fn new_mutable<S: AsRef<str>>(data: &[(S, S)]) -> MutableMemoryConfigurationProvider {
+    MutableMemoryConfigurationProvider::new(
+        data.iter()
+            .map(|t| {
+                let key = t.0.as_ref();
+                (key.to_uppercase(), (key.to_owned(), t.1.as_ref().to_owned().into()))
+            })
+            .collect(),
+    )
+}
+
 pub mod ext {
 
     use super::*;
@@ -83,6 +238,21 @@ pub mod ext {
         ///
         /// * `data` - The data to add to memory configuration provider
         fn add_in_memory<S: AsRef<str>>(&mut self, data: &[(S, S)]) -> &mut Self;
+
+        /// Adds a mutable in-memory configuration source using the specified data.
+        ///
+        /// # Arguments
+        ///
+        /// * `data` - The initial data of the memory configuration provider
+        ///
+        /// # Remarks
+        ///
+        /// The returned [`MutableMemoryConfigurationProvider`] shares its data with the
+        /// provider in the configuration and can be used to change values at runtime.
+        fn add_in_memory_mutable<S: AsRef<str>>(
+            &mut self,
+            data: &[(S, S)],
+        ) -> MutableMemoryConfigurationProvider;
     }
 
     impl MemoryConfigurationBuilderExtensions for dyn ConfigurationBuilder {
@@ -90,6 +260,15 @@ pub mod ext {
             self.add(Box::new(MemoryConfigurationSource::new(data)));
             self
         }
+
+        fn add_in_memory_mutable<S: AsRef<str>>(
+            &mut self,
+            data: &[(S, S)],
+        ) -> MutableMemoryConfigurationProvider {
+            let provider = new_mutable(data);
+            self.add(Box::new(MutableMemoryConfigurationSource::new(provider.clone())));
+            provider
+        }
     }
 
     impl<T: ConfigurationBuilder> MemoryConfigurationBuilderExtensions for T {
@@ -97,5 +276,14 @@ pub mod ext {
             self.add(Box::new(MemoryConfigurationSource::new(data)));
             self
         }
+
+        fn add_in_memory_mutable<S: AsRef<str>>(
+            &mut self,
+            data: &[(S, S)],
+        ) -> MutableMemoryConfigurationProvider {
+            let provider = new_mutable(data);
+            self.add(Box::new(MutableMemoryConfigurationSource::new(provider.clone())));
+            provider
+        }
     }
 }
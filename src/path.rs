@@ -51,6 +51,55 @@ impl ConfigurationPath {
             EMPTY
         }
     }
+
+    /// Extracts the path corresponding to the parent node for a given path, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to extract the parent path from
+    ///
+    /// # Remarks
+    ///
+    /// Unlike [`parent_path`](ConfigurationPath::parent_path), [`None`] is returned
+    /// if the path does not have a parent.
+    pub fn parent(path: &str) -> Option<&str> {
+        path.rfind(KEY_DELIMITER).map(|index| &path[..index])
+    }
+
+    /// Splits the specified path into its segments.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to split
+    ///
+    /// # Remarks
+    ///
+    /// This is the inverse of [`combine`](ConfigurationPath::combine) and empty segments are retained.
+    pub fn split(path: &str) -> Vec<&str> {
+        if path.is_empty() {
+            Vec::new()
+        } else {
+            path.split(KEY_DELIMITER).collect()
+        }
+    }
+
+    /// Normalizes the specified path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to normalize
+    ///
+    /// # Remarks
+    ///
+    /// Each segment is trimmed of whitespace and empty segments, such as those
+    /// created by repeated delimiters, are removed.
+    pub fn normalize(path: &str) -> String {
+        path.split(KEY_DELIMITER)
+            .map(str::trim)
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>()
+            .join(KEY_DELIMITER)
+    }
 }
 
 #[cfg(test)]
@@ -107,4 +156,47 @@ mod tests {
         // assert
         assert_eq!(key, expected);
     }
+
+    #[test_case("", None ; "when empty")]
+    #[test_case("key", None ; "with no parent")]
+    #[test_case(":key", Some("") ; "with 1 empty parent")]
+    #[test_case("a:b:c", Some("a:b") ; "with parents")]
+    fn parent_should_return_expected_path(path: &str, expected: Option<&str>) {
+        // arrange
+
+        // act
+        let parent = ConfigurationPath::parent(path);
+
+        // assert
+        assert_eq!(parent, expected);
+    }
+
+    #[test_case("", &[] ; "when empty")]
+    #[test_case("key", &["key"] ; "with 1 segment")]
+    #[test_case("a:b:c", &["a", "b", "c"] ; "with multiple segments")]
+    #[test_case("a::b:", &["a", "", "b", ""] ; "with empty segments")]
+    fn split_should_return_expected_segments(path: &str, expected: &[&str]) {
+        // arrange
+
+        // act
+        let segments = ConfigurationPath::split(path);
+
+        // assert
+        assert_eq!(&segments, expected);
+    }
+
+    #[test_case("", "" ; "when empty")]
+    #[test_case(":::", "" ; "when only delimiters")]
+    #[test_case("a::b:::c", "a:b:c" ; "with repeated delimiters")]
+    #[test_case(":a:b:", "a:b" ; "with leading and trailing delimiters")]
+    #[test_case(" a : b ", "a:b" ; "with whitespace")]
+    fn normalize_should_return_expected_path(path: &str, expected: &str) {
+        // arrange
+
+        // act
+        let normalized = ConfigurationPath::normalize(path);
+
+        // assert
+        assert_eq!(&normalized, expected);
+    }
 }
@@ -0,0 +1,181 @@
+use crate::{
+    util::cmp_keys, ConfigurationBuilder, ConfigurationPath, ConfigurationProvider,
+    ConfigurationSource, LoadResult, Value,
+};
+use tokens::ChangeToken;
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) that
+/// mounts the keys of another provider under a prefix.
+pub struct PrefixedConfigurationProvider {
+    prefix: String,
+    provider: Box<dyn ConfigurationProvider>,
+}
+
+impl PrefixedConfigurationProvider {
+    /// Initializes a new prefixed configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix path the keys of the provider are mounted under
+    /// * `provider` - The [`ConfigurationProvider`](crate::ConfigurationProvider) to mount
+    pub fn new<S: AsRef<str>>(prefix: S, provider: Box<dyn ConfigurationProvider>) -> Self {
+        Self {
+            prefix: ConfigurationPath::normalize(prefix.as_ref()),
+            provider,
+        }
+    }
+
+    // gets the remainder of the key after the prefix path, if it has one
+    fn strip<'a>(&self, key: &'a str) -> Option<&'a str> {
+        if self.prefix.is_empty() {
+            Some(key)
+        } else {
+            strip_path(key, &self.prefix)
+        }
+    }
+
+    // the prefix segments are only keys when the mounted provider contributes at least one key
+    fn is_empty(&self) -> bool {
+        let mut keys = Vec::new();
+        self.provider.child_keys(&mut keys, None);
+        keys.is_empty()
+    }
+}
+
+fn strip_path<'a>(key: &'a str, path: &str) -> Option<&'a str> {
+    let delimiter = ConfigurationPath::key_delimiter();
+    let length = path.len();
+
+    if key.len() > length
+        && key.is_char_boundary(length)
+        && key[..length].to_uppercase() == path.to_uppercase()
+        && key[length..].starts_with(delimiter)
+    {
+        Some(&key[length + delimiter.len()..])
+    } else {
+        None
+    }
+}
+
+impl ConfigurationProvider for PrefixedConfigurationProvider {
+    fn name(&self) -> &str {
+        self.provider.name()
+    }
+
+    fn get(&self, key: &str) -> Option<Value> {
+        self.strip(key).and_then(|key| self.provider.get(key))
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.provider.reload_token()
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.provider.load()
+    }
+
+    fn stage(&mut self) -> LoadResult {
+        self.provider.stage()
+    }
+
+    fn commit(&mut self) {
+        self.provider.commit()
+    }
+
+    fn discard(&mut self) {
+        self.provider.discard()
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        let delimiter = ConfigurationPath::key_delimiter();
+
+        if self.prefix.is_empty() {
+            return self.provider.child_keys(earlier_keys, parent_path);
+        }
+
+        if let Some(path) = parent_path {
+            if path.to_uppercase() == self.prefix.to_uppercase() {
+                return self.provider.child_keys(earlier_keys, None);
+            } else if let Some(subpath) = self.strip(path) {
+                return self.provider.child_keys(earlier_keys, Some(subpath));
+            } else if let Some(rest) = strip_path(&self.prefix, path).filter(|_| !self.is_empty()) {
+                // the parent is an ancestor of the prefix
+                earlier_keys.push(rest.split(delimiter).next().unwrap().to_owned());
+            }
+        } else if !self.is_empty() {
+            earlier_keys.push(self.prefix.split(delimiter).next().unwrap().to_owned());
+        }
+
+        earlier_keys.sort_by(|k1, k2| cmp_keys(k1, k2));
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) that mounts
+/// the keys of another source under a prefix.
+pub struct PrefixedConfigurationSource {
+    prefix: String,
+    source: Box<dyn ConfigurationSource>,
+}
+
+impl PrefixedConfigurationSource {
+    /// Initializes a new prefixed configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix path the keys of the source are mounted under
+    /// * `source` - The [`ConfigurationSource`](crate::ConfigurationSource) to mount
+    pub fn new<S: Into<String>>(prefix: S, source: Box<dyn ConfigurationSource>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            source,
+        }
+    }
+}
+
+impl ConfigurationSource for PrefixedConfigurationSource {
+    fn build(&self, builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(PrefixedConfigurationProvider::new(
+            &self.prefix,
+            self.source.build(builder),
+        ))
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait PrefixedBuilderExtensions {
+        /// Adds a configuration source whose keys are mounted under a prefix.
+        ///
+        /// # Arguments
+        ///
+        /// * `prefix` - The prefix path the keys of the source are mounted under
+        /// * `source` - The [`ConfigurationSource`](crate::ConfigurationSource) to mount
+        fn add_prefixed(&mut self, prefix: &str, source: Box<dyn ConfigurationSource>)
+            -> &mut Self;
+    }
+
+    impl PrefixedBuilderExtensions for dyn ConfigurationBuilder {
+        fn add_prefixed(
+            &mut self,
+            prefix: &str,
+            source: Box<dyn ConfigurationSource>,
+        ) -> &mut Self {
+            self.add(Box::new(PrefixedConfigurationSource::new(prefix, source)));
+            self
+        }
+    }
+
+    impl<T: ConfigurationBuilder> PrefixedBuilderExtensions for T {
+        fn add_prefixed(
+            &mut self,
+            prefix: &str,
+            source: Box<dyn ConfigurationSource>,
+        ) -> &mut Self {
+            self.add(Box::new(PrefixedConfigurationSource::new(prefix, source)));
+            self
+        }
+    }
+}
@@ -62,10 +62,36 @@ pub trait ConfigurationProvider {
     }
 
     /// Loads the configuration values from the implemented source.
+    ///
+    /// # Remarks
+    ///
+    /// A provider is not required to materialize all of its values when it is loaded. A provider may
+    /// defer fetching values until they are requested by [`get`](ConfigurationProvider::get), provided
+    /// that values cached before a load are not returned after it.
     fn load(&mut self) -> LoadResult {
         Ok(())
     }
 
+    /// Loads the configuration values from the implemented source without replacing the values
+    /// currently provided.
+    ///
+    /// # Remarks
+    ///
+    /// The staged values are provided once [`commit`](ConfigurationProvider::commit) is called and are
+    /// dropped by [`discard`](ConfigurationProvider::discard). The default implementation calls
+    /// [`load`](ConfigurationProvider::load), which means the values of a provider that does not stage
+    /// its values are replaced immediately.
+    fn stage(&mut self) -> LoadResult {
+        self.load()
+    }
+
+    /// Replaces the values currently provided with the values loaded by
+    /// [`stage`](ConfigurationProvider::stage).
+    fn commit(&mut self) {}
+
+    /// Discards the values loaded by [`stage`](ConfigurationProvider::stage).
+    fn discard(&mut self) {}
+
     /// Gets the immediate descendent configuration keys for a given parent path based
     /// on this [`ConfigurationProvider`] and the set of keys returned by all of the
     /// preceding [`ConfigurationProvider`].
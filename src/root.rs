@@ -1,9 +1,14 @@
-use crate::{Configuration, ConfigurationProvider, LoadError};
+use crate::{Configuration, ConfigurationProvider, LoadError, Value};
 use std::fmt::{Debug, Formatter, Result as FormatResult};
 use std::{borrow::Borrow, ops::Deref};
 
 /// Defines the possible reload errors.
+///
+/// # Remarks
+///
+/// Matching on a reload error requires a wildcard arm because variants may be added in the future.
 #[derive(PartialEq, Clone)]
+#[non_exhaustive]
 pub enum ReloadError {
     /// Indicates one or more provider load errors occurred.
     Provider(Vec<(String, LoadError)>),
@@ -12,6 +17,27 @@ pub enum ReloadError {
     /// are borrowed references. The number of references
     /// may be reported if known.
     Borrowed(Option<usize>),
+
+    /// Indicates a reload was stopped by the first provider load error
+    /// when using [`ReloadPolicy::FailFast`].
+    Stopped {
+        /// Gets the names of the providers that were loaded before the error occurred. The values they
+        /// loaded were discarded.
+        loaded: Vec<String>,
+
+        /// Gets the name of the provider that failed and its load error.
+        error: (String, LoadError),
+    },
+
+    /// Indicates a chain of key aliases loops back to one of its own keys.
+    /// The keys that form the cycle are reported in order.
+    AliasCycle(Vec<String>),
+
+    /// Indicates one or more required configuration keys do not have a value.
+    MissingKeys(Vec<String>),
+
+    /// Indicates the configuration root does not support changing its providers.
+    Unsupported,
 }
 
 impl Debug for ReloadError {
@@ -37,12 +63,46 @@ impl Debug for ReloadError {
 
                 write!(f, " outstanding borrow references.")?;
             }
+            Self::Stopped { loaded, error } => {
+                write!(f, "{} ({})", error.1.message(), &error.0)?;
+
+                if loaded.is_empty() {
+                    f.write_str("\nReload stopped before any providers were loaded.")?;
+                } else {
+                    write!(f, "\nReload stopped after loading: {}", loaded.join(", "))?;
+                }
+            }
+            Self::AliasCycle(keys) => {
+                write!(f, "The configuration key aliases form a cycle: {}", keys.join(" -> "))?;
+            }
+            Self::MissingKeys(keys) => {
+                write!(f, "The required configuration keys do not have a value: {}", keys.join(", "))?;
+            }
+            Self::Unsupported => {
+                f.write_str("The configuration root does not support changing its providers.")?;
+            }
         }
 
         Ok(())
     }
 }
 
+/// Defines the behaviors used when a provider fails to load during a reload.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReloadPolicy {
+    /// Indicates a reload stops at the first provider error. Each provider is
+    /// [staged](crate::ConfigurationProvider::stage) and the staged values are only
+    /// committed once every provider has loaded successfully. Otherwise, the staged
+    /// values are discarded, listeners are not notified, and the providers loaded
+    /// before the error occurred are reported in the error.
+    FailFast,
+
+    /// Indicates all providers are loaded, the reload token is signaled, and any errors
+    /// are reported afterward. This is the default behavior.
+    #[default]
+    ContinueOnError,
+}
+
 /// Represents a configuration reload result.
 pub type ReloadResult = std::result::Result<(), ReloadError>;
 
@@ -58,11 +118,79 @@ pub trait ConfigurationRoot:
     /// [`ConfigurationProvider`](crate::ConfigurationProvider) collection.
     fn reload(&mut self) -> ReloadResult;
 
+    /// Adds a [`ConfigurationProvider`](crate::ConfigurationProvider) to the configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `provider` - The [`ConfigurationProvider`](crate::ConfigurationProvider) to add
+    ///
+    /// # Remarks
+    ///
+    /// The provider is loaded before it is added. The provider is appended and has the highest precedence
+    /// so its values override the values of every existing provider. Listeners of the
+    /// [reload token](crate::Configuration::reload_token) are notified when the provider is added.
+    /// The default implementation fails with [`ReloadError::Unsupported`].
+    fn add_provider(&mut self, provider: Box<dyn ConfigurationProvider>) -> ReloadResult {
+        let _ = provider;
+        Err(ReloadError::Unsupported)
+    }
+
+    /// Removes every [`ConfigurationProvider`](crate::ConfigurationProvider) with the specified name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the provider to remove
+    ///
+    /// # Remarks
+    ///
+    /// Providers are matched by [`name`](crate::ConfigurationProvider::name), which is the type name of
+    /// the provider unless it is overridden, so every provider of the same type is removed by default.
+    /// Listeners of the [reload token](crate::Configuration::reload_token) are notified if any
+    /// provider is removed. The default implementation fails with [`ReloadError::Unsupported`].
+    fn remove_provider(&mut self, name: &str) -> ReloadResult {
+        let _ = name;
+        Err(ReloadError::Unsupported)
+    }
+
     /// Gets the [`ConfigurationProvider`](crate::ConfigurationProvider) sequence for this configuration.
     fn providers(&self) -> Box<dyn ConfigurationProviderIterator + '_>;
 
     /// Converts the [`ConfigurationRoot`] into a [`Configuration`](crate::Configuration).
     fn as_config(&self) -> Box<dyn Configuration>;
+
+    /// Gets a configuration value and the name of the
+    /// [`ConfigurationProvider`](crate::ConfigurationProvider) that supplied it.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The configuration key
+    ///
+    /// # Remarks
+    ///
+    /// The value is returned exactly as it was supplied by the provider.
+    fn get_with_source(&self, key: &str) -> Option<(Value, String)> {
+        self.providers()
+            .rev()
+            .find_map(|provider| provider.get(key).map(|value| (value, provider.name().to_owned())))
+    }
+
+    /// Gets the name of every [`ConfigurationProvider`](crate::ConfigurationProvider) and the
+    /// value it supplies for a configuration key.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The configuration key
+    ///
+    /// # Remarks
+    ///
+    /// The providers are listed in precedence order. The first provider with a value is the one
+    /// that supplies the value of the configuration.
+    fn explain(&self, key: &str) -> Vec<(String, Option<Value>)> {
+        self.providers()
+            .rev()
+            .map(|provider| (provider.name().to_owned(), provider.get(key)))
+            .collect()
+    }
 }
 
 /// Defines the behavior of an iterator over a
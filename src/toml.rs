@@ -0,0 +1,184 @@
+use crate::FileSource;
+use crate::{
+    ConfigurationBuilder, ConfigurationPath, ConfigurationProvider, ConfigurationSource,
+    FileConfigurationParser, FileConfigurationProvider, LoadResult, Value,
+};
+use std::collections::HashMap;
+use std::path::Path;
+use tokens::ChangeToken;
+use toml_rs::{Table, Value as TomlValue};
+
+fn visit_table(table: &Table, path: Option<String>, data: &mut HashMap<String, (String, Value)>) {
+    if table.is_empty() {
+        if let Some(key) = path {
+            data.insert(key.to_uppercase(), (key, String::new().into()));
+        }
+    } else {
+        for (key, value) in table {
+            let key = match path {
+                Some(ref path) => ConfigurationPath::combine(&[path, key]),
+                _ => key.clone(),
+            };
+
+            visit(value, key, data);
+        }
+    }
+}
+
+// an array of tables is an array whose items are tables, so 'name:0:field' falls out naturally
+fn visit(value: &TomlValue, path: String, data: &mut HashMap<String, (String, Value)>) {
+    let value = match value {
+        TomlValue::Table(table) => return visit_table(table, Some(path), data),
+        TomlValue::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                visit(
+                    item,
+                    ConfigurationPath::combine(&[&path, &index.to_string()]),
+                    data,
+                );
+            }
+
+            return;
+        }
+        TomlValue::String(value) => value.clone(),
+        TomlValue::Float(value) if value.is_nan() => "NaN".into(),
+        TomlValue::Float(value) => value.to_string(),
+        TomlValue::Integer(value) => value.to_string(),
+        TomlValue::Boolean(value) => value.to_string(),
+        TomlValue::Datetime(value) => value.to_string(),
+    };
+
+    data.insert(path.to_uppercase(), (path, value.into()));
+}
+
+struct TomlParser;
+
+impl FileConfigurationParser for TomlParser {
+    fn parse(
+        &self,
+        content: &[u8],
+        path: &Path,
+    ) -> Result<HashMap<String, (String, Value)>, String> {
+        let content = String::from_utf8(content.to_vec()).map_err(|e| {
+            format!(
+                "The configuration file '{}' could not be read. {}",
+                path.display(),
+                e
+            )
+        })?;
+        let root: Table = content.parse().map_err(|e: toml_rs::de::Error| {
+            let offset = e.span().map_or(0, |span| span.start);
+            let line = content[..offset].matches('\n').count() + 1;
+
+            format!(
+                "Could not parse the TOML file '{}'. {} (Line: {})",
+                path.display(),
+                e.message().trim_end(),
+                line
+            )
+        })?;
+        let mut data = HashMap::new();
+
+        visit_table(&root, None, &mut data);
+        Ok(data)
+    }
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for `*.toml` files.
+pub struct TomlConfigurationProvider {
+    inner: FileConfigurationProvider<TomlParser>,
+}
+
+impl TomlConfigurationProvider {
+    /// Initializes a new `*.toml` file configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The `*.toml` [`FileSource`](crate::FileSource) information
+    pub fn new(file: FileSource) -> Self {
+        Self {
+            inner: FileConfigurationProvider::new(file, TomlParser),
+        }
+    }
+}
+
+impl ConfigurationProvider for TomlConfigurationProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.inner.get(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.inner.reload_token()
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.inner.load()
+    }
+
+    fn stage(&mut self) -> LoadResult {
+        self.inner.stage()
+    }
+
+    fn commit(&mut self) {
+        self.inner.commit()
+    }
+
+    fn discard(&mut self) {
+        self.inner.discard()
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.inner.child_keys(earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for `*.toml` files.
+pub struct TomlConfigurationSource {
+    file: FileSource,
+}
+
+impl TomlConfigurationSource {
+    /// Initializes a new `*.toml` file configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The `*.toml` [`FileSource`](crate::FileSource) information
+    pub fn new(file: FileSource) -> Self {
+        Self { file }
+    }
+}
+
+impl ConfigurationSource for TomlConfigurationSource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(TomlConfigurationProvider::new(self.file.clone()))
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait TomlConfigurationExtensions {
+        /// Adds a `*.toml` file as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `file` - The `*.toml` [`FileSource`](crate::FileSource) information
+        fn add_toml_file<T: Into<FileSource>>(&mut self, file: T) -> &mut Self;
+    }
+
+    impl TomlConfigurationExtensions for dyn ConfigurationBuilder {
+        fn add_toml_file<T: Into<FileSource>>(&mut self, file: T) -> &mut Self {
+            self.add(Box::new(TomlConfigurationSource::new(file.into())));
+            self
+        }
+    }
+
+    impl<T: ConfigurationBuilder> TomlConfigurationExtensions for T {
+        fn add_toml_file<F: Into<FileSource>>(&mut self, file: F) -> &mut Self {
+            self.add(Box::new(TomlConfigurationSource::new(file.into())));
+            self
+        }
+    }
+}
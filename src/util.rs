@@ -124,6 +124,155 @@ fn segment(key: &str, start: usize) -> &str {
     }
 }
 
+/// Parses a connection string into its key/value pairs.
+///
+/// # Arguments
+///
+/// * `connection_string` - The connection string to parse
+///
+/// # Remarks
+///
+/// Key/value pairs are delimited by `;` and each key is separated from its value by `=`.
+/// A value may be enclosed in single or double quotes so that it can contain `;`. A quote
+/// within a quoted value is escaped by doubling it. The returned keys are normalized to
+/// uppercase so that they can be matched case-insensitively.
+pub fn parse_connection_string(connection_string: &Value) -> HashMap<String, String> {
+    let mut pairs = HashMap::new();
+    let mut chars = connection_string.chars().peekable();
+
+    loop {
+        let mut key = String::new();
+
+        while let Some(ch) = chars.next_if(|c| *c != '=' && *c != ';') {
+            key.push(ch);
+        }
+
+        let mut value = String::new();
+
+        if chars.next_if_eq(&'=').is_some() {
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+
+            if let Some(quote) = chars.next_if(|c| *c == '"' || *c == '\'') {
+                while let Some(ch) = chars.next() {
+                    if ch != quote {
+                        value.push(ch);
+                    } else if chars.next_if_eq(&quote).is_some() {
+                        value.push(quote);
+                    } else {
+                        break;
+                    }
+                }
+
+                while chars.next_if(|c| *c != ';').is_some() {}
+            } else {
+                while let Some(ch) = chars.next_if(|c| *c != ';') {
+                    value.push(ch);
+                }
+
+                value = value.trim_end().to_owned();
+            }
+        }
+
+        let key = key.trim();
+
+        if !key.is_empty() {
+            pairs.insert(key.to_uppercase(), value);
+        }
+
+        if chars.next().is_none() {
+            break;
+        }
+    }
+
+    pairs
+}
+
+const REDACTED: &str = "****";
+
+/// Represents a set of key patterns whose values are sensitive and must be redacted.
+///
+/// # Remarks
+///
+/// A pattern containing `*` is a glob that must match the entire key, where `*` matches
+/// any sequence of characters. Any other pattern matches a key that contains it. Patterns
+/// are matched against absolute keys without regard to case.
+#[derive(Clone, Debug, Default)]
+pub struct RedactionLayer {
+    patterns: Vec<String>,
+}
+
+impl RedactionLayer {
+    /// Initializes a new redaction layer.
+    ///
+    /// # Arguments
+    ///
+    /// * `patterns` - The key patterns whose values are sensitive
+    pub fn new<S: AsRef<str>>(patterns: &[S]) -> Self {
+        Self {
+            patterns: patterns.iter().map(|p| p.as_ref().to_uppercase()).collect(),
+        }
+    }
+
+    /// Gets a value indicating whether the value of the specified key is sensitive.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The absolute key to evaluate
+    pub fn is_sensitive(&self, key: &str) -> bool {
+        let key = key.to_uppercase();
+
+        self.patterns.iter().any(|pattern| {
+            if pattern.contains('*') {
+                glob_match(pattern.as_bytes(), key.as_bytes())
+            } else {
+                key.contains(pattern.as_str())
+            }
+        })
+    }
+
+    /// Redacts the specified value if the key is sensitive.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The absolute key of the value
+    /// * `value` - The value to redact
+    ///
+    /// # Remarks
+    ///
+    /// An empty value, such as the value of a section that only contains other keys, is never redacted.
+    pub fn redact(&self, key: &str, value: Value) -> Value {
+        if !value.is_empty() && self.is_sensitive(key) {
+            REDACTED.to_owned().into()
+        } else {
+            value
+        }
+    }
+}
+
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack = None;
+
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == text[t] {
+            p += 1;
+            t += 1;
+        } else if let Some((star, matched)) = backtrack {
+            // let the last wildcard consume one more character
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == b'*')
+}
+
 /// Formats a debug view of an entire configuration hierarchy.
 ///
 /// # Arguments
@@ -134,12 +283,31 @@ pub fn fmt_debug_view<T>(root: &T, formatter: &mut Formatter<'_>) -> FormatResul
 where
     T: ConfigurationRoot,
 {
-    recurse_children(root, &root.children(), formatter, "")
+    recurse_children(root, &root.children(), None, formatter, "")
+}
+
+/// Formats a debug view of an entire configuration hierarchy with sensitive values redacted.
+///
+/// # Arguments
+///
+/// * `root` - The [`ConfigurationRoot`] to format
+/// * `redaction` - The [`RedactionLayer`] used to redact sensitive values
+/// * `formatter` - The formatter used to output the configuration
+pub fn fmt_redacted_debug_view<T>(
+    root: &T,
+    redaction: &RedactionLayer,
+    formatter: &mut Formatter<'_>,
+) -> FormatResult
+where
+    T: ConfigurationRoot,
+{
+    recurse_children(root, &root.children(), Some(redaction), formatter, "")
 }
 
 fn recurse_children<T: ConfigurationRoot>(
     root: &T,
     children: &[Box<dyn ConfigurationSection>],
+    redaction: Option<&RedactionLayer>,
     formatter: &mut Formatter<'_>,
     indent: &str,
 ) -> FormatResult {
@@ -152,7 +320,13 @@ fn recurse_children<T: ConfigurationRoot>(
         for provider in root.providers().rev() {
             if let Some(value) = provider.get(child.path()) {
                 formatter.write_char('=')?;
-                formatter.write_str(&value)?;
+
+                if !value.is_empty() && redaction.is_some_and(|r| r.is_sensitive(child.path())) {
+                    formatter.write_str(REDACTED)?;
+                } else {
+                    formatter.write_str(&value)?;
+                }
+
                 formatter.write_str(" (")?;
                 formatter.write_str(provider.name())?;
                 formatter.write_char(')')?;
@@ -170,6 +344,7 @@ fn recurse_children<T: ConfigurationRoot>(
         recurse_children(
             root,
             &child.children(),
+            redaction,
             formatter,
             &(indent.to_owned() + "  "),
         )?;
@@ -182,6 +357,7 @@ fn recurse_children<T: ConfigurationRoot>(
 mod tests {
 
     use super::*;
+    use test_case::test_case;
 
     #[test]
     fn to_pascal_case_should_normalize_argument_name() {
@@ -206,4 +382,50 @@ mod tests {
         // assert
         assert_eq!(pascal_case, "NoBuild");
     }
+
+    #[test_case("Host=localhost;Port=5432;User=admin", "PORT", "5432" ; "with simple value")]
+    #[test_case(" Host = localhost ; Port = 5432 ", "HOST", "localhost" ; "with whitespace")]
+    #[test_case("Host=localhost;Password=\"p;w=d\"", "PASSWORD", "p;w=d" ; "with double quoted value")]
+    #[test_case("Host=localhost;Password='it''s'", "PASSWORD", "it's" ; "with escaped quote")]
+    #[test_case("host=localhost;;", "HOST", "localhost" ; "with empty segments")]
+    fn parse_connection_string_should_return_expected_value(text: &str, key: &str, expected: &str) {
+        // arrange
+        let connection_string = Value::new(text.to_owned());
+
+        // act
+        let pairs = parse_connection_string(&connection_string);
+
+        // assert
+        assert_eq!(pairs.get(key).unwrap(), expected);
+    }
+
+    #[test]
+    fn parse_connection_string_should_return_all_pairs() {
+        // arrange
+        let connection_string = Value::new("Host=localhost;Port=5432;User=admin;".to_owned());
+
+        // act
+        let pairs = parse_connection_string(&connection_string);
+
+        // assert
+        assert_eq!(pairs.len(), 3);
+    }
+
+    #[test_case("*Password*", "Database:Password", true ; "with glob in the middle")]
+    #[test_case("*Password*", "Database:PasswordFile", true ; "with glob suffix")]
+    #[test_case("ConnectionStrings:*", "connectionstrings:default", true ; "with glob prefix")]
+    #[test_case("ConnectionStrings:*", "Logging:ConnectionStrings", false ; "with glob prefix elsewhere")]
+    #[test_case("*:Key", "Section:Other", false ; "with unmatched glob")]
+    #[test_case("Secret", "Api:ClientSecret", true ; "with substring")]
+    #[test_case("Secret", "Api:ClientId", false ; "with unmatched substring")]
+    fn is_sensitive_should_match_pattern(pattern: &str, key: &str, expected: bool) {
+        // arrange
+        let redaction = RedactionLayer::new(&[pattern]);
+
+        // act
+        let sensitive = redaction.is_sensitive(key);
+
+        // assert
+        assert_eq!(sensitive, expected);
+    }
 }
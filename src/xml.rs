@@ -1,15 +1,13 @@
 use crate::{
-    util::*, ConfigurationBuilder, ConfigurationPath, ConfigurationProvider, ConfigurationSource,
-    FileSource, LoadError, LoadResult, Value,
+    ConfigurationBuilder, ConfigurationPath, ConfigurationProvider, ConfigurationSource,
+    FileConfigurationParser, FileConfigurationProvider, FileSource, LoadResult, Value,
 };
 use std::cell::RefCell;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::BufReader;
 use std::ops::Deref;
+use std::path::Path;
 use std::rc::Rc;
-use std::sync::{Arc, RwLock};
-use tokens::{ChangeToken, FileChangeToken, SharedChangeToken, SingleChangeToken, Subscription};
+use tokens::ChangeToken;
 use xml_rs::attribute::OwnedAttribute;
 use xml_rs::name::OwnedName;
 use xml_rs::reader::{EventReader, XmlEvent};
@@ -98,6 +96,29 @@ impl Element {
                 .collect::<Result<Vec<Attribute>, String>>()?,
         })
     }
+
+    // an element in the form <add key="Key" value="Value" /> describes a single setting
+    // of its parent rather than a nested element
+    fn setting(&self) -> Option<(&str, &str)> {
+        if !self.element_name.eq_ignore_ascii_case("add")
+            || self.attributes.len() != 2
+            || !self.children.is_empty()
+            || self.text.is_some()
+        {
+            return None;
+        }
+
+        let key = self
+            .attributes
+            .iter()
+            .find(|a| a.0.eq_ignore_ascii_case("key"))?;
+        let value = self
+            .attributes
+            .iter()
+            .find(|a| a.0.eq_ignore_ascii_case("value"))?;
+
+        Some((&key.1, &value.1))
+    }
 }
 
 #[derive(Default)]
@@ -226,11 +247,25 @@ fn process_children(
     config: &mut HashMap<String, (String, Value)>,
 ) -> Result<(), String> {
     for children in element.children.iter().map(|i| &i.1) {
-        if children.len() == 1 {
-            process_element_child(prefix, &children[0].deref().borrow(), None, config)?;
+        let mut elements = Vec::with_capacity(children.len());
+
+        for child in children {
+            let child = child.deref().borrow();
+
+            if let Some((key, value)) = child.setting() {
+                prefix.push(key);
+                add_to_config(prefix.to_string(), value.to_owned(), &child, config)?;
+                prefix.pop();
+            } else {
+                elements.push(child);
+            }
+        }
+
+        if elements.len() == 1 {
+            process_element_child(prefix, &elements[0], None, config)?;
         } else {
-            for (i, child) in children.iter().enumerate() {
-                process_element_child(prefix, &child.deref().borrow(), Some(i), config)?;
+            for (i, child) in elements.iter().enumerate() {
+                process_element_child(prefix, child, Some(i), config)?;
             }
         }
     }
@@ -274,8 +309,7 @@ fn to_config(
     }
 }
 
-fn visit(file: File) -> Result<HashMap<String, (String, Value)>, String> {
-    let content = BufReader::new(file);
+fn visit(content: &[u8], path: &Path) -> Result<HashMap<String, (String, Value)>, String> {
     let events = EventReader::new(content);
     let mut has_content = false;
     let mut last_name = None;
@@ -327,6 +361,13 @@ fn visit(file: File) -> Result<HashMap<String, (String, Value)>, String> {
                     parent.1.borrow_mut().text = Some(text);
                 }
             }
+            Err(error) => {
+                return Err(format!(
+                    "Could not parse the XML file '{}'. {}",
+                    path.display(),
+                    error
+                ))
+            }
             _ => {}
         };
     }
@@ -334,82 +375,21 @@ fn visit(file: File) -> Result<HashMap<String, (String, Value)>, String> {
     to_config(root)
 }
 
-struct InnerProvider {
-    file: FileSource,
-    data: RwLock<HashMap<String, (String, Value)>>,
-    token: RwLock<SharedChangeToken<SingleChangeToken>>,
-}
+struct XmlParser;
 
-impl InnerProvider {
-    fn new(file: FileSource) -> Self {
-        Self {
-            file,
-            data: RwLock::new(HashMap::with_capacity(0)),
-            token: Default::default(),
-        }
-    }
-
-    fn load(&self, reload: bool) -> LoadResult {
-        if !self.file.path.is_file() {
-            if self.file.optional || reload {
-                let mut data = self.data.write().unwrap();
-                if !data.is_empty() {
-                    *data = HashMap::with_capacity(0);
-                }
-
-                return Ok(());
-            } else {
-                return Err(LoadError::File {
-                    message: format!(
-                        "The configuration file '{}' was not found and is not optional.",
-                        self.file.path.display()
-                    ),
-                    path: self.file.path.clone(),
-                });
-            }
-        }
-
-        if let Ok(file) = File::open(&self.file.path) {
-            let data = visit(file).map_err(|e| LoadError::File {
-                message: e,
-                path: self.file.path.clone(),
-            })?;
-            *self.data.write().unwrap() = data;
-        } else {
-            *self.data.write().unwrap() = HashMap::with_capacity(0);
-        }
-
-        let previous = std::mem::replace(
-            &mut *self.token.write().unwrap(),
-            SharedChangeToken::default(),
-        );
-
-        previous.notify();
-        Ok(())
-    }
-
-    fn get(&self, key: &str) -> Option<Value> {
-        self.data
-            .read()
-            .unwrap()
-            .get(&key.to_uppercase())
-            .map(|t| t.1.clone())
-    }
-
-    fn reload_token(&self) -> Box<dyn ChangeToken> {
-        Box::new(self.token.read().unwrap().clone())
-    }
-
-    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
-        let data = self.data.read().unwrap();
-        accumulate_child_keys(&data, earlier_keys, parent_path)
+impl FileConfigurationParser for XmlParser {
+    fn parse(
+        &self,
+        content: &[u8],
+        path: &Path,
+    ) -> Result<HashMap<String, (String, Value)>, String> {
+        visit(content, path)
     }
 }
 
 /// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for `*.xml` files.
 pub struct XmlConfigurationProvider {
-    inner: Arc<InnerProvider>,
-    _subscription: Option<Box<dyn Subscription>>,
+    inner: FileConfigurationProvider<XmlParser>,
 }
 
 impl XmlConfigurationProvider {
@@ -419,25 +399,8 @@ impl XmlConfigurationProvider {
     ///
     /// * `file` - The `*.xml` [`FileSource`](crate::FileSource) information
     pub fn new(file: FileSource) -> Self {
-        let path = file.path.clone();
-        let inner = Arc::new(InnerProvider::new(file));
-        let subscription: Option<Box<dyn Subscription>> = if inner.file.reload_on_change {
-            Some(Box::new(tokens::on_change(
-                move || FileChangeToken::new(path.clone()),
-                |state| {
-                    let provider = state.unwrap();
-                    std::thread::sleep(provider.file.reload_delay);
-                    provider.load(true).ok();
-                },
-                Some(inner.clone()),
-            )))
-        } else {
-            None
-        };
-
         Self {
-            inner,
-            _subscription: subscription,
+            inner: FileConfigurationProvider::new(file, XmlParser),
         }
     }
 }
@@ -452,7 +415,19 @@ impl ConfigurationProvider for XmlConfigurationProvider {
     }
 
     fn load(&mut self) -> LoadResult {
-        self.inner.load(false)
+        self.inner.load()
+    }
+
+    fn stage(&mut self) -> LoadResult {
+        self.inner.stage()
+    }
+
+    fn commit(&mut self) {
+        self.inner.commit()
+    }
+
+    fn discard(&mut self) {
+        self.inner.discard()
     }
 
     fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
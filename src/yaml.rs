@@ -0,0 +1,224 @@
+use crate::FileSource;
+use crate::{
+    ConfigurationBuilder, ConfigurationPath, ConfigurationProvider, ConfigurationSource,
+    FileConfigurationParser, FileConfigurationProvider, LoadResult, Value,
+};
+use std::collections::HashMap;
+use std::path::Path;
+use tokens::ChangeToken;
+use yaml_rust2::{yaml::Hash, Yaml, YamlLoader};
+
+const MERGE_KEY: &str = "<<";
+
+// converts a scalar into the text the typed accessors expect; for example, '.inf' becomes 'inf'
+fn scalar(yaml: &Yaml) -> Option<String> {
+    let value = match yaml {
+        Yaml::String(value) => value.clone(),
+        Yaml::Integer(value) => value.to_string(),
+        Yaml::Boolean(value) => value.to_string(),
+        Yaml::Real(value) => match value.as_str() {
+            ".inf" | ".Inf" | ".INF" | "+.inf" | "+.Inf" | "+.INF" => "inf".into(),
+            "-.inf" | "-.Inf" | "-.INF" => "-inf".into(),
+            ".nan" | ".NaN" | ".NAN" => "NaN".into(),
+            _ => value.trim_start_matches('+').to_owned(),
+        },
+        Yaml::Null => String::new(),
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+// keys that are defined explicitly take precedence over the keys of a merged mapping
+fn merge<'a>(mapping: &'a Hash, entries: &mut Vec<(String, &'a Yaml)>) {
+    let mut merged = Vec::new();
+
+    for (key, value) in mapping {
+        if let Some(key) = scalar(key) {
+            if key == MERGE_KEY {
+                merged.push(value);
+            } else if !entries.iter().any(|(other, _)| other == &key) {
+                entries.push((key, value));
+            }
+        }
+    }
+
+    for value in merged {
+        match value {
+            Yaml::Hash(mapping) => merge(mapping, entries),
+            Yaml::Array(items) => {
+                for item in items {
+                    if let Yaml::Hash(mapping) = item {
+                        merge(mapping, entries);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn visit(yaml: &Yaml, path: Option<String>, data: &mut HashMap<String, (String, Value)>) {
+    let combine = |key: &str| match path {
+        Some(ref path) => ConfigurationPath::combine(&[path, key]),
+        _ => key.to_owned(),
+    };
+
+    match yaml {
+        Yaml::Hash(mapping) if mapping.is_empty() => {
+            if let Some(ref key) = path {
+                data.insert(key.to_uppercase(), (key.clone(), String::new().into()));
+            }
+        }
+        Yaml::Hash(mapping) => {
+            let mut entries = Vec::with_capacity(mapping.len());
+
+            merge(mapping, &mut entries);
+
+            for (key, value) in entries {
+                visit(value, Some(combine(&key)), data);
+            }
+        }
+        Yaml::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                visit(item, Some(combine(&index.to_string())), data);
+            }
+        }
+        _ => {
+            if let (Some(key), Some(value)) = (path, scalar(yaml)) {
+                data.insert(key.to_uppercase(), (key, value.into()));
+            }
+        }
+    }
+}
+
+struct YamlParser;
+
+impl FileConfigurationParser for YamlParser {
+    fn parse(
+        &self,
+        content: &[u8],
+        path: &Path,
+    ) -> Result<HashMap<String, (String, Value)>, String> {
+        let content = String::from_utf8(content.to_vec()).map_err(|e| {
+            format!(
+                "The configuration file '{}' could not be read. {}",
+                path.display(),
+                e
+            )
+        })?;
+        let error = |e| format!("Could not parse the YAML file '{}'. {}", path.display(), e);
+        let documents = YamlLoader::load_from_str(&content)
+            .map_err(|e| error(format!("{} (Line: {})", e.info(), e.marker().line())))?;
+        let mut data = HashMap::new();
+
+        match documents.as_slice() {
+            [] | [Yaml::Null] => Ok(data),
+            [root @ Yaml::Hash(_)] => {
+                visit(root, None, &mut data);
+                Ok(data)
+            }
+            [_] => Err(error("Top-level YAML element must be a mapping.".into())),
+            _ => Err(error("A YAML file must contain a single document.".into())),
+        }
+    }
+}
+
+/// Represents a [`ConfigurationProvider`](crate::ConfigurationProvider) for `*.yaml` and `*.yml` files.
+pub struct YamlConfigurationProvider {
+    inner: FileConfigurationProvider<YamlParser>,
+}
+
+impl YamlConfigurationProvider {
+    /// Initializes a new `*.yaml` file configuration provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The `*.yaml` [`FileSource`](crate::FileSource) information
+    pub fn new(file: FileSource) -> Self {
+        Self {
+            inner: FileConfigurationProvider::new(file, YamlParser),
+        }
+    }
+}
+
+impl ConfigurationProvider for YamlConfigurationProvider {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.inner.get(key)
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        self.inner.reload_token()
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.inner.load()
+    }
+
+    fn stage(&mut self) -> LoadResult {
+        self.inner.stage()
+    }
+
+    fn commit(&mut self) {
+        self.inner.commit()
+    }
+
+    fn discard(&mut self) {
+        self.inner.discard()
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        self.inner.child_keys(earlier_keys, parent_path)
+    }
+}
+
+/// Represents a [`ConfigurationSource`](crate::ConfigurationSource) for `*.yaml` and `*.yml` files.
+pub struct YamlConfigurationSource {
+    file: FileSource,
+}
+
+impl YamlConfigurationSource {
+    /// Initializes a new `*.yaml` file configuration source.
+    ///
+    /// # Arguments
+    ///
+    /// * `file` - The `*.yaml` [`FileSource`](crate::FileSource) information
+    pub fn new(file: FileSource) -> Self {
+        Self { file }
+    }
+}
+
+impl ConfigurationSource for YamlConfigurationSource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(YamlConfigurationProvider::new(self.file.clone()))
+    }
+}
+
+pub mod ext {
+
+    use super::*;
+
+    /// Defines extension methods for [`ConfigurationBuilder`](crate::ConfigurationBuilder).
+    pub trait YamlConfigurationExtensions {
+        /// Adds a `*.yaml` or `*.yml` file as a configuration source.
+        ///
+        /// # Arguments
+        ///
+        /// * `file` - The `*.yaml` [`FileSource`](crate::FileSource) information
+        fn add_yaml_file<T: Into<FileSource>>(&mut self, file: T) -> &mut Self;
+    }
+
+    impl YamlConfigurationExtensions for dyn ConfigurationBuilder {
+        fn add_yaml_file<T: Into<FileSource>>(&mut self, file: T) -> &mut Self {
+            self.add(Box::new(YamlConfigurationSource::new(file.into())));
+            self
+        }
+    }
+
+    impl<T: ConfigurationBuilder> YamlConfigurationExtensions for T {
+        fn add_yaml_file<F: Into<FileSource>>(&mut self, file: F) -> &mut Self {
+            self.add(Box::new(YamlConfigurationSource::new(file.into())));
+            self
+        }
+    }
+}
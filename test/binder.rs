@@ -117,6 +117,45 @@ fn bind_at_should_deserialize_configuration_to_options() {
     assert_eq!(options.phones.len(), 2);
 }
 
+#[test]
+fn try_reify_should_report_path_of_invalid_value() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Contact:name", "John Doe"),
+            ("Contact:primary", "maybe"),
+            ("Contact:phones:0", "+44 1234567"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let result = config.section("Contact").try_reify::<ContactOptions>();
+
+    // assert
+    assert_eq!(
+        result.err().unwrap().to_string(),
+        "provided string was not `true` or `false` while parsing value 'maybe' provided by Contact:primary"
+    );
+}
+
+#[test]
+fn try_bind_at_should_ignore_missing_section() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("name", "John Doe")])
+        .build()
+        .unwrap();
+    let mut options = ContactOptions::default();
+
+    // act
+    let result = config.try_bind_at("Contact", &mut options);
+
+    // assert
+    assert!(result.is_ok());
+    assert!(options.name.is_empty());
+}
+
 #[test]
 fn get_value_should_deserialize_configuration_value() {
     // arrange
@@ -195,7 +234,7 @@ fn deserialization_should_preserve_case_in_ini_file() {
     file.write_all(b"start.bat=1").unwrap();
 
     let config = DefaultConfigurationBuilder::new()
-        .add_ini_file(&path)
+        .add_ini_file_with_nested_keys(&path, true)
         .build()
         .unwrap();
 
@@ -0,0 +1,87 @@
+use config::{ext::*, *};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+#[test]
+fn chained_configuration_should_participate_in_override_order() {
+    // arrange
+    let inner = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Key1", "Inner1"), ("Key2", "Inner2")])
+        .build()
+        .unwrap();
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Key1", "Outer1"), ("Key3", "Outer3")])
+        .add_configuration(inner.as_config())
+        .add_in_memory(&[("Key2", "Outer2")])
+        .build()
+        .unwrap();
+
+    // act
+    let key1 = config.get("Key1").unwrap();
+    let key2 = config.get("Key2").unwrap();
+    let key3 = config.get("Key3").unwrap();
+
+    // assert
+    assert_eq!(key1.as_str(), "Inner1");
+    assert_eq!(key2.as_str(), "Outer2");
+    assert_eq!(key3.as_str(), "Outer3");
+}
+
+#[test]
+fn chained_configuration_should_enumerate_child_keys() {
+    // arrange
+    let inner = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Section:Key1", "Value1"), ("Section:Key2", "Value2")])
+        .build()
+        .unwrap();
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Section:Key3", "Value3")])
+        .add_configuration(inner.as_config())
+        .build()
+        .unwrap();
+
+    // act
+    let mut keys: Vec<_> = config
+        .section("Section")
+        .children()
+        .iter()
+        .map(|c| c.key().to_owned())
+        .collect();
+
+    keys.sort();
+
+    // assert
+    assert_eq!(keys, vec!["Key1", "Key2", "Key3"]);
+}
+
+#[test]
+fn chained_configuration_should_propagate_reload() {
+    // arrange
+    let mut inner = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Key1", "Value1")])
+        .build()
+        .unwrap();
+    let config = DefaultConfigurationBuilder::new()
+        .add_configuration(inner.as_config())
+        .build()
+        .unwrap();
+    let reloaded = Arc::<AtomicBool>::default();
+    let _unused = config.reload_token().register(
+        Box::new(|state| {
+            state
+                .unwrap()
+                .downcast_ref::<AtomicBool>()
+                .unwrap()
+                .store(true, Ordering::SeqCst)
+        }),
+        Some(reloaded.clone()),
+    );
+
+    // act
+    inner.reload().unwrap();
+
+    // assert
+    assert!(reloaded.load(Ordering::SeqCst));
+}
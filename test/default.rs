@@ -432,3 +432,899 @@ fn key_ending_with_double_colon_has_section_with_empty_name() {
     assert_eq!(children[0].children()[0].children().len(), 1);
     assert_eq!(children[0].children()[0].children()[0].key(), "Key3");
 }
+
+#[test]
+fn connection_string_should_return_named_value() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("ConnectionStrings:Default", "Host=localhost;Port=5432")])
+        .build()
+        .unwrap();
+
+    // act
+    let connection_string = config.connection_string("default").unwrap();
+    let pairs = parse_connection_string(&connection_string);
+
+    // assert
+    assert_eq!(connection_string.as_str(), "Host=localhost;Port=5432");
+    assert_eq!(pairs.get("HOST").unwrap(), "localhost");
+    assert!(config.connection_string("Other").is_none());
+}
+
+#[test]
+fn to_sorted_pairs_should_return_merged_leaf_values_ordered_by_key() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Service:Name", "Demo"), ("Service:Port", "80"), ("Debug", "false")])
+        .add_in_memory(&[("service:port", "8080")])
+        .build()
+        .unwrap();
+
+    // act
+    let pairs = config.to_sorted_pairs();
+
+    // assert
+    let keys: Vec<_> = pairs.iter().map(|(key, _)| key.to_uppercase()).collect();
+    let values: Vec<_> = pairs.iter().map(|(_, value)| value.as_str()).collect();
+    assert_eq!(keys, vec!["DEBUG", "SERVICE:NAME", "SERVICE:PORT"]);
+    assert_eq!(values, vec!["false", "Demo", "8080"]);
+}
+
+#[test]
+fn to_sorted_pairs_should_order_keys_without_regard_to_case() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("beta", "2"), ("Gamma", "3"), ("alpha", "1"), ("Delta", "4")])
+        .build()
+        .unwrap();
+
+    // act
+    let pairs = config.to_sorted_pairs();
+
+    // assert
+    let keys: Vec<_> = pairs.iter().map(|(key, _)| key.as_str()).collect();
+    assert_eq!(keys, vec!["alpha", "beta", "Delta", "Gamma"]);
+}
+
+#[test]
+fn add_defaults_should_be_overridden_by_existing_sources() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory(&[("Key", "Value")]);
+    builder.add_defaults(Box::new(MemoryConfigurationSource::new(&[
+        ("Key", "Default"),
+        ("Other", "Default"),
+    ])));
+
+    // act
+    let config = builder.build().unwrap();
+
+    // assert
+    assert_eq!(config.get("Key").unwrap().as_str(), "Value");
+    assert_eq!(config.get("Other").unwrap().as_str(), "Default");
+}
+
+#[test]
+fn insert_should_add_source_at_precedence_position() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory(&[("Key", "Value1")]);
+    builder.add_in_memory(&[("Key", "Value3")]);
+    builder.insert(
+        1,
+        Box::new(MemoryConfigurationSource::new(&[("Key", "Value2"), ("Other", "Value2")])),
+    );
+
+    // act
+    let config = builder.build().unwrap();
+
+    // assert
+    assert_eq!(config.get("Key").unwrap().as_str(), "Value3");
+    assert_eq!(config.get("Other").unwrap().as_str(), "Value2");
+    assert_eq!(config.providers().count(), 3);
+}
+
+#[test]
+fn insert_should_add_source_last_when_index_is_source_count() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory(&[("Key", "Value1")]);
+    builder.insert(1, Box::new(MemoryConfigurationSource::new(&[("Key", "Value2")])));
+
+    // act
+    let config = builder.build().unwrap();
+
+    // assert
+    assert_eq!(config.get("Key").unwrap().as_str(), "Value2");
+    assert_eq!(config.providers().count(), 2);
+}
+
+#[test]
+#[should_panic(expected = "The index 2 is greater than the number of configuration sources (1).")]
+fn insert_should_panic_when_index_is_out_of_range() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory(&[("Key", "Value1")]);
+
+    // act
+    builder.insert(2, Box::new(MemoryConfigurationSource::new(&[("Key", "Value2")])));
+
+    // assert
+}
+
+#[test]
+fn leaves_should_return_keys_with_values() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Service:Name", "Demo"),
+            ("Service:Endpoint", "http://localhost"),
+            ("Service:Endpoint:Port", "80"),
+            ("Service:Tags", ""),
+        ])
+        .build()
+        .unwrap();
+    let section = config.section("Service");
+
+    // act
+    let mut leaves: Vec<_> = section
+        .leaves(true, true)
+        .map(|(key, value)| (key, value.to_string()))
+        .collect();
+
+    // assert
+    leaves.sort();
+    assert_eq!(
+        leaves,
+        vec![
+            ("Endpoint".into(), "http://localhost".into()),
+            ("Endpoint:Port".into(), "80".into()),
+            ("Name".into(), "Demo".into()),
+            ("Tags".into(), String::new()),
+        ]
+    );
+}
+
+#[test]
+fn leaves_should_exclude_empty_values_when_requested() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Service:Name", "Demo"),
+            ("Service:Endpoint", "http://localhost"),
+            ("Service:Endpoint:Port", "80"),
+            ("Service:Tags", ""),
+        ])
+        .build()
+        .unwrap();
+    let section = config.section("Service");
+
+    // act
+    let mut leaves: Vec<_> = section
+        .leaves(true, false)
+        .map(|(key, value)| (key, value.to_string()))
+        .collect();
+
+    // assert
+    leaves.sort();
+    assert_eq!(
+        leaves,
+        vec![
+            ("Endpoint".into(), "http://localhost".into()),
+            ("Endpoint:Port".into(), "80".into()),
+            ("Name".into(), "Demo".into()),
+        ]
+    );
+}
+
+#[test]
+fn get_should_substitute_placeholders_when_enabled() {
+    // arrange
+    std::env::set_var("MORE_CONFIG_TEST_HOST", "localhost");
+
+    let config = DefaultConfigurationBuilder::new()
+        .enable_substitution()
+        .add_in_memory(&[
+            ("Port", "5432"),
+            ("Database:Host", "${ENV:MORE_CONFIG_TEST_HOST}"),
+            ("Database:Url", "db://${Database:Host}:${Port}/${Missing}"),
+            ("Escaped", "$${Port}"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let url = config.section("Database").get("Url").unwrap();
+    let escaped = config.get("Escaped").unwrap();
+
+    // assert
+    assert_eq!(url.as_str(), "db://localhost:5432/${Missing}");
+    assert_eq!(escaped.as_str(), "${Port}");
+}
+
+#[test]
+fn get_should_not_substitute_placeholders_by_default() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Key1", "Value1"), ("Key2", "${Key1}")])
+        .build()
+        .unwrap();
+
+    // act
+    let value = config.get("Key2").unwrap();
+
+    // assert
+    assert_eq!(value.as_str(), "${Key1}");
+}
+
+#[test]
+fn get_should_return_literal_when_placeholders_are_cyclic() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .enable_substitution()
+        .add_in_memory(&[("Key1", "a${Key2}"), ("Key2", "b${Key1}")])
+        .build()
+        .unwrap();
+
+    // act
+    let value = config.get("Key1").unwrap();
+
+    // assert
+    assert_eq!(value.as_str(), "a${Key2}");
+}
+
+#[test]
+fn get_should_substitute_placeholders_with_non_ascii_names() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .enable_substitution()
+        .add_in_memory(&[
+            ("Café", "open"),
+            ("Key1", "${Café}"),
+            ("Key2", "${aéé}"),
+            ("Key3", "${ENVé}"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let key1 = config.get("Key1").unwrap();
+    let key2 = config.get("Key2").unwrap();
+    let key3 = config.get("Key3").unwrap();
+
+    // assert
+    assert_eq!(key1.as_str(), "open");
+    assert_eq!(key2.as_str(), "${aéé}");
+    assert_eq!(key3.as_str(), "${ENVé}");
+}
+
+#[test]
+fn get_required_section_should_return_existing_section() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Service:Name", "Demo"), ("Empty", "")])
+        .build()
+        .unwrap();
+
+    // act
+    let section = config.get_required_section("Service").unwrap();
+
+    // assert
+    assert_eq!(section.get("Name").unwrap().as_str(), "Demo");
+}
+
+#[test_case("Missing" ; "absent")]
+#[test_case("Empty" ; "empty")]
+fn get_required_section_should_fail_when_section_does_not_exist(key: &str) {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Service:Name", "Demo"), ("Empty", "")])
+        .build()
+        .unwrap();
+
+    // act
+    let result = config.get_required_section(key);
+
+    // assert
+    assert_eq!(result.err().unwrap().path(), key);
+}
+
+#[test_case("true", Some(true) ; "lowercase true")]
+#[test_case("FALSE", Some(false) ; "uppercase false")]
+#[test_case(" Yes ", Some(true) ; "yes")]
+#[test_case("no", Some(false) ; "no")]
+#[test_case("1", Some(true) ; "one")]
+#[test_case("0", Some(false) ; "zero")]
+#[test_case("  ", None ; "whitespace")]
+fn get_bool_should_parse_value(value: &str, expected: Option<bool>) {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Enabled", value)])
+        .build()
+        .unwrap();
+
+    // act
+    let enabled = config.get_bool("Enabled").unwrap();
+
+    // assert
+    assert_eq!(enabled, expected);
+}
+
+#[test]
+fn typed_getters_should_parse_values() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Offset", "-42"),
+            ("Port", " 8080 "),
+            ("Ratio", "0.5"),
+            ("Name", " Demo "),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let offset = config.get_i64("Offset").unwrap();
+    let port = config.get_u64("Port").unwrap();
+    let ratio = config.get_f64("Ratio").unwrap();
+    let name = config.get_string("Name").unwrap();
+    let missing = config.get_u64("Missing").unwrap();
+
+    // assert
+    assert_eq!(offset, Some(-42));
+    assert_eq!(port, Some(8080));
+    assert_eq!(ratio, Some(0.5));
+    assert_eq!(name.as_deref(), Some("Demo"));
+    assert_eq!(missing, None);
+}
+
+#[test]
+fn typed_getter_should_report_key_and_value_when_parsing_fails() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Service:Port", "eighty")])
+        .build()
+        .unwrap();
+
+    // act
+    let error = config.section("Service").get_u64("Port").unwrap_err();
+
+    // assert
+    assert_eq!(error.key(), "Port");
+    assert_eq!(error.value(), "eighty");
+    assert_eq!(
+        error.to_string(),
+        "The value 'eighty' for key 'Port' is not a valid u64."
+    );
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn build_shared_should_return_root_that_can_be_used_across_threads() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+    let provider = builder.add_in_memory_mutable(&[("Key", "Value1")]);
+    let mut root = builder.build_shared().unwrap();
+    let clone = root.clone();
+
+    // act
+    let value = std::thread::spawn(move || {
+        provider.set("Key", "Value2");
+        root.reload().unwrap();
+        root.get("Key").unwrap().to_string()
+    })
+    .join()
+    .unwrap();
+
+    // assert
+    assert_eq!(value, "Value2");
+    assert_eq!(clone.get("Key").unwrap().as_str(), "Value2");
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn reload_should_fail_when_shared_root_is_being_read() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+    builder.add_in_memory(&[("Key", "Value")]);
+    let root = builder.build_shared().unwrap();
+    let mut clone = root.clone();
+    let providers = root.providers();
+
+    // act
+    let result = clone.reload();
+
+    // assert
+    drop(providers);
+    assert!(matches!(result, Err(ReloadError::Borrowed(_))));
+    assert!(clone.reload().is_ok());
+}
+
+#[test]
+fn get_with_source_should_return_value_from_last_provider() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory(&[("Key", "Value1")]);
+    builder.add_in_memory_mutable(&[("Key", "Value2")]);
+    builder.add_in_memory(&[("Other", "Value3")]);
+
+    let config = builder.build().unwrap();
+
+    // act
+    let (value, source) = config.get_with_source("Key").unwrap();
+
+    // assert
+    assert_eq!(value.as_str(), "Value2");
+    assert_eq!(source, config.providers().nth(1).unwrap().name());
+}
+
+#[test]
+fn explain_should_list_provider_values_in_precedence_order() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Key", "Value1")])
+        .add_in_memory(&[("Other", "Value2")])
+        .add_in_memory(&[("Key", "Value3")])
+        .build()
+        .unwrap();
+
+    // act
+    let values: Vec<_> = config
+        .explain("Key")
+        .into_iter()
+        .map(|(_, value)| value.map(|v| v.to_string()))
+        .collect();
+
+    // assert
+    assert_eq!(
+        values,
+        vec![Some("Value3".to_owned()), None, Some("Value1".to_owned())]
+    );
+}
+
+#[test]
+fn redaction_should_hide_sensitive_values_in_debug_and_export() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .redact(RedactionLayer::new(&["*Password*", "ConnectionStrings:*"]))
+        .add_in_memory(&[
+            ("Database:Host", "localhost"),
+            ("Database:Password", "p@ssw0rd"),
+            ("ConnectionStrings:Default", "Host=localhost"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let debug = format!("{:?}", config);
+    let map: HashMap<_, _> = config.to_sorted_pairs().into_iter().collect();
+    let relative: Vec<_> = config.section("Database").iter(Some(Relative)).collect();
+
+    // assert
+    assert!(!debug.contains("p@ssw0rd"));
+    assert!(debug.contains("localhost"));
+    assert_eq!(map["Database:Password"], "****");
+    assert_eq!(map["ConnectionStrings:Default"], "****");
+    assert_eq!(map["Database:Host"], "localhost");
+    assert!(relative
+        .iter()
+        .any(|(key, value)| key == "Password" && value.as_str() == "****"));
+    assert_eq!(config.get("Database:Password").unwrap().as_str(), "p@ssw0rd");
+}
+
+#[test]
+fn redaction_should_not_change_exported_keys() {
+    // arrange
+    let data = [("Secrets:Api", "x"), ("Secrets:Token", "y"), ("Name", "Demo")];
+    let plain = DefaultConfigurationBuilder::new()
+        .add_in_memory(&data)
+        .build()
+        .unwrap();
+    let redacted = DefaultConfigurationBuilder::new()
+        .redact(RedactionLayer::new(&["Secret"]))
+        .add_in_memory(&data)
+        .build()
+        .unwrap();
+    let keys = |config: &dyn ConfigurationRoot| {
+        let pairs: Vec<_> = config.to_sorted_pairs().into_iter().map(|(key, _)| key).collect();
+        let mut leaves: Vec<_> = config.leaves(false, true).map(|(key, _)| key).collect();
+
+        leaves.sort();
+        (pairs, leaves)
+    };
+
+    // act
+    let expected = keys(plain.as_ref());
+    let actual = keys(redacted.as_ref());
+
+    // assert
+    assert_eq!(actual, expected);
+    assert_eq!(expected.0, vec!["Name", "Secrets:Api", "Secrets:Token"]);
+}
+
+#[test]
+fn on_change_should_invoke_callback_after_each_reload() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+    builder.add_in_memory(&[("Key", "Value")]);
+    let mut root = builder.build().unwrap();
+    let count = std::sync::Arc::new(std::sync::atomic::AtomicUsize::default());
+    let counter = count.clone();
+    let registration = root.on_change(move || {
+        counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    });
+
+    // act
+    root.reload().unwrap();
+    root.reload().unwrap();
+    drop(registration);
+    root.reload().unwrap();
+
+    // assert
+    assert_eq!(count.load(std::sync::atomic::Ordering::SeqCst), 2);
+}
+
+#[test]
+fn on_change_should_invoke_callback_when_section_is_reloaded() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+    builder.add_in_memory(&[("Service:Port", "80")]);
+    let mut root = builder.build().unwrap();
+    let section = root.section("Service").as_config();
+    let config: &dyn Configuration = section.as_ref();
+    let count = std::sync::Arc::new(std::sync::atomic::AtomicUsize::default());
+    let counter = count.clone();
+    let _registration = config.on_change(move || {
+        counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    });
+
+    // act
+    root.reload().unwrap();
+
+    // assert
+    assert_eq!(count.load(std::sync::atomic::Ordering::SeqCst), 1);
+}
+
+#[test]
+fn on_change_should_invoke_callback_when_provider_changes() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+    let provider = builder.add_in_memory_mutable(&[("Key", "Value1")]);
+    let root = builder.build().unwrap();
+    let count = std::sync::Arc::new(std::sync::atomic::AtomicUsize::default());
+    let counter = count.clone();
+    let _registration = root.on_change(move || {
+        counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    });
+
+    // act
+    provider.set("Key", "Value2");
+
+    // assert
+    assert_eq!(count.load(std::sync::atomic::Ordering::SeqCst), 1);
+    assert_eq!(root.get("Key").unwrap().as_str(), "Value2");
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn on_change_should_invoke_callback_each_time_provider_changes_after_token_is_renewed() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+    let provider = builder.add_in_memory_mutable(&[("Key", "Value1")]);
+    let root = builder.build_shared().unwrap();
+    let count = std::sync::Arc::new(std::sync::atomic::AtomicUsize::default());
+    let counter = count.clone();
+    let _registration = root.on_change(move || {
+        counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    });
+    let set = |value: &'static str| {
+        let provider = provider.clone();
+        std::thread::spawn(move || provider.set("Key", value))
+            .join()
+            .unwrap()
+    };
+
+    // act
+    set("Value2");
+    set("Value3");
+    root.reload_token();
+    set("Value4");
+
+    // assert
+    assert_eq!(count.load(std::sync::atomic::Ordering::SeqCst), 2);
+    assert_eq!(root.get("Key").unwrap().as_str(), "Value4");
+}
+
+#[test]
+fn get_should_read_alias_target_when_alias_is_unset() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_alias("Legacy:Timeout", "New:Timeout")
+        .add_alias("Legacy:Retries", "New:Retries")
+        .add_alias("Old:Retries", "Legacy:Retries")
+        .add_in_memory(&[
+            ("New:Timeout", "30"),
+            ("New:Retries", "3"),
+            ("Legacy:Retries", "5"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let timeout = config.section("Legacy").get("Timeout");
+    let retries = config.get("Legacy:Retries");
+    let chained = config.get("old:retries");
+    let children: Vec<_> = config
+        .section("Legacy")
+        .children()
+        .iter()
+        .map(|s| s.key().to_owned())
+        .collect();
+
+    // assert
+    assert_eq!(timeout.unwrap().as_str(), "30");
+    assert_eq!(retries.unwrap().as_str(), "5");
+    assert_eq!(chained.unwrap().as_str(), "5");
+    assert_eq!(children, vec!["Retries"]);
+}
+
+#[test]
+fn build_should_fail_when_aliases_form_a_cycle() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder
+        .add_alias("A", "B")
+        .add_alias("B", "C")
+        .add_alias("C", "a")
+        .add_in_memory(&[("Key", "Value")]);
+
+    // act
+    let result = builder.build();
+
+    // assert
+    if let Err(ReloadError::AliasCycle(keys)) = result {
+        assert_eq!(keys, vec!["A", "B", "C", "A"]);
+    } else {
+        panic!("The expected error did not occur.")
+    }
+}
+
+#[test]
+fn diff_should_return_added_removed_and_changed_keys() {
+    // arrange
+    let before = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("Service:Name", "Demo"),
+            ("Service:Port", "80"),
+            ("Service:Tags", ""),
+            ("Debug", "false"),
+        ])
+        .build()
+        .unwrap();
+    let after = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("debug", "false"),
+            ("Service:Port", "8080"),
+            ("Service:Name", "Demo"),
+            ("Service:Host", ""),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let diff = before.diff(after.as_ref());
+
+    // assert
+    assert_eq!(diff.added(), &[("Service:Host".to_owned(), String::new())]);
+    assert_eq!(diff.removed(), &[("Service:Tags".to_owned(), String::new())]);
+    assert_eq!(
+        diff.changed(),
+        &[("Service:Port".to_owned(), "80".to_owned(), "8080".to_owned())]
+    );
+    assert!(before.diff(before.as_ref()).is_empty());
+}
+
+#[test]
+fn diff_should_compare_values_before_they_are_redacted() {
+    // arrange
+    let build = |password| {
+        DefaultConfigurationBuilder::new()
+            .redact(RedactionLayer::new(&["*Password*"]))
+            .add_in_memory(&[("Database:Host", "localhost"), ("Database:Password", password)])
+            .build()
+            .unwrap()
+    };
+    let before = build("p@ssw0rd");
+    let after = build("s3cr3t");
+
+    // act
+    let diff = before.diff(after.as_ref());
+
+    // assert
+    assert!(diff.added().is_empty());
+    assert!(diff.removed().is_empty());
+    assert_eq!(diff.changed().len(), 1);
+    assert_eq!(diff.changed()[0].0, "Database:Password");
+    assert!(!diff.changed()[0].1.contains("p@ssw0rd"));
+    assert!(!diff.changed()[0].2.contains("s3cr3t"));
+    assert!(before.diff(build("p@ssw0rd").as_ref()).is_empty());
+}
+
+fn memory_provider(data: &[(&str, &str)]) -> Box<dyn ConfigurationProvider> {
+    MemoryConfigurationSource::new(data).build(&DefaultConfigurationBuilder::new())
+}
+
+#[test]
+fn add_provider_should_override_existing_providers() {
+    // arrange
+    let mut root = DefaultConfigurationRoot::new(vec![memory_provider(&[("Theme", "Light")])]).unwrap();
+    let token = root.reload_token();
+
+    // act
+    root.add_provider(memory_provider(&[("Theme", "Dark")])).unwrap();
+
+    // assert
+    assert_eq!(root.get("Theme").unwrap().as_str(), "Dark");
+    assert_eq!(root.providers().count(), 2);
+    assert!(token.changed());
+}
+
+#[test]
+fn remove_provider_should_remove_providers_with_name() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add_in_memory_mutable(&[("Theme", "Light")]);
+
+    let mut root = builder.build().unwrap();
+    let name = root.providers().next().unwrap().name().to_owned();
+
+    root.add_provider(memory_provider(&[("Theme", "Dark")])).unwrap();
+
+    let override_name = root.providers().last().unwrap().name().to_owned();
+    let token = root.reload_token();
+
+    // act
+    root.remove_provider(&override_name).unwrap();
+
+    // assert
+    assert_eq!(root.get("Theme").unwrap().as_str(), "Light");
+    assert_eq!(root.providers().next().unwrap().name(), name);
+    assert_eq!(root.providers().count(), 1);
+    assert!(token.changed());
+}
+
+#[test]
+fn add_provider_should_fail_when_providers_are_borrowed() {
+    // arrange
+    let mut root = DefaultConfigurationRoot::new(vec![memory_provider(&[("Key", "Value")])]).unwrap();
+    let clone = root.clone();
+    let providers = clone.providers();
+
+    // act
+    let result = root.add_provider(memory_provider(&[("Key", "Other")]));
+
+    // assert
+    drop(providers);
+    assert!(matches!(result, Err(ReloadError::Borrowed(_))));
+    assert_eq!(root.providers().count(), 1);
+}
+
+#[derive(Clone, Debug)]
+struct ReadOnlyConfigurationRoot(DefaultConfigurationRoot);
+
+impl Configuration for ReadOnlyConfigurationRoot {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.0.get(key)
+    }
+
+    fn section(&self, key: &str) -> Box<dyn ConfigurationSection> {
+        self.0.section(key)
+    }
+
+    fn children(&self) -> Vec<Box<dyn ConfigurationSection>> {
+        self.0.children()
+    }
+
+    fn reload_token(&self) -> Box<dyn tokens::ChangeToken> {
+        self.0.reload_token()
+    }
+
+    fn iter(&self, path: Option<ConfigurationPath>) -> Box<dyn Iterator<Item = (String, Value)>> {
+        self.0.iter(path)
+    }
+}
+
+impl ConfigurationRoot for ReadOnlyConfigurationRoot {
+    fn reload(&mut self) -> ReloadResult {
+        self.0.reload()
+    }
+
+    fn providers(&self) -> Box<dyn ConfigurationProviderIterator + '_> {
+        self.0.providers()
+    }
+
+    fn as_config(&self) -> Box<dyn Configuration> {
+        Box::new(self.clone())
+    }
+}
+
+impl<'a> AsRef<dyn Configuration + 'a> for ReadOnlyConfigurationRoot {
+    fn as_ref(&self) -> &(dyn Configuration + 'a) {
+        self
+    }
+}
+
+impl<'a> std::borrow::Borrow<dyn Configuration + 'a> for ReadOnlyConfigurationRoot {
+    fn borrow(&self) -> &(dyn Configuration + 'a) {
+        self
+    }
+}
+
+impl std::ops::Deref for ReadOnlyConfigurationRoot {
+    type Target = dyn Configuration;
+
+    fn deref(&self) -> &Self::Target {
+        self
+    }
+}
+
+#[test]
+fn add_and_remove_provider_should_be_unsupported_by_default() {
+    // arrange
+    let root = DefaultConfigurationRoot::new(vec![memory_provider(&[("Key", "Value")])]).unwrap();
+    let mut root = ReadOnlyConfigurationRoot(root);
+    let name = root.providers().next().unwrap().name().to_owned();
+
+    // act
+    let added = root.add_provider(memory_provider(&[("Key", "Other")]));
+    let removed = root.remove_provider(&name);
+
+    // assert
+    assert!(matches!(added, Err(ReloadError::Unsupported)));
+    assert!(matches!(removed, Err(ReloadError::Unsupported)));
+    assert_eq!(root.get("Key").unwrap().as_str(), "Value");
+}
+
+#[test]
+fn build_should_fail_when_required_keys_do_not_have_values() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder
+        .require_keys(&["Db:Host", "Db:Password"])
+        .require_keys(&["Db:Port", "Logging:Level"])
+        .add_in_memory(&[("Db:Host", "localhost"), ("Db:Port", " ")]);
+
+    // act
+    let result = builder.build();
+
+    // assert
+    if let Err(ReloadError::MissingKeys(keys)) = result {
+        assert_eq!(keys, vec!["Db:Password", "Db:Port", "Logging:Level"]);
+    } else {
+        panic!("The expected error did not occur.")
+    }
+}
+
+#[test]
+fn build_should_succeed_when_required_keys_have_values() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder
+        .require_keys(&["db:host"])
+        .add_in_memory(&[("Db:Host", "localhost")]);
+
+    // act
+    let result = builder.build();
+
+    // assert
+    assert!(result.is_ok());
+}
@@ -0,0 +1,142 @@
+use config::{ext::*, *};
+use std::env::temp_dir;
+use std::fs::{remove_file, File};
+use std::io::Write;
+use std::path::PathBuf;
+use test_case::test_case;
+
+#[test]
+fn add_dotenv_file_should_load_settings_from_file() {
+    // arrange
+    let path = temp_dir().join("test_settings_1.env");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(b"# local development settings\n").unwrap();
+    file.write_all(b"HOST=localhost # inline comment\n").unwrap();
+    file.write_all(b"export PORT=5432\n\n").unwrap();
+    file.write_all(b"Database__Url=\"db://${HOST}:${PORT}\\n\"\n").unwrap();
+    file.write_all(b"Database__Password='p@$${word}#1'\n").unwrap();
+    file.write_all(b"Tag=a#b").unwrap();
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_dotenv_file(&path)
+        .build()
+        .unwrap();
+    let section = config.section("Database");
+
+    // act
+    let host = config.get("Host");
+    let port = config.get("Port");
+    let url = section.get("Url");
+    let password = section.get("Password");
+    let tag = config.get("Tag");
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert_eq!(host.unwrap().as_str(), "localhost");
+    assert_eq!(port.unwrap().as_str(), "5432");
+    assert_eq!(url.unwrap().as_str(), "db://localhost:5432\n");
+    assert_eq!(password.unwrap().as_str(), "p@$${word}#1");
+    assert_eq!(tag.unwrap().as_str(), "a#b");
+}
+
+#[test_case(true, "Logging:Level" ; "with nested keys")]
+#[test_case(false, "Logging__Level" ; "without nested keys")]
+fn add_dotenv_file_with_nested_keys_should_translate_double_underscores(
+    nested_keys: bool,
+    key: &str,
+) {
+    // arrange
+    let path = temp_dir().join(format!("test_settings_nested_{}.env", nested_keys));
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(b"Logging__Level=Debug\n").unwrap();
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_dotenv_file_with_nested_keys(&path, nested_keys)
+        .build()
+        .unwrap();
+
+    // act
+    let value = config.get(key);
+    let children = config.children().len();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert_eq!(value.unwrap().as_str(), "Debug");
+    assert_eq!(children, 1);
+}
+
+#[test]
+fn add_dotenv_file_should_fail_if_file_does_not_exist() {
+    // arrange
+    let path = PathBuf::from(r"C:\fake\.env");
+
+    // act
+    let result = DefaultConfigurationBuilder::new()
+        .add_dotenv_file(&path)
+        .build();
+
+    // assert
+    if let Err(ReloadError::Provider(errors)) = result {
+        assert_eq!(
+            errors[0].1.message(),
+            r"The configuration file 'C:\fake\.env' was not found and is not optional."
+        )
+    } else {
+        panic!("The expected error did not occur.")
+    }
+}
+
+#[test]
+fn add_dotenv_file_should_not_fail_if_optional_file_does_not_exist() {
+    // arrange
+    let path = PathBuf::from(r"C:\fake\.env");
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_dotenv_file(path.is().optional())
+        .build()
+        .unwrap();
+
+    // assert
+    assert_eq!(config.children().len(), 0);
+}
+
+#[test]
+fn add_dotenv_file_should_fail_if_line_is_malformed() {
+    // arrange
+    let path = temp_dir().join("test_settings_2.env");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(b"KEY=value\n").unwrap();
+    file.write_all(b"OTHER=\"unterminated\n").unwrap();
+
+    // act
+    let result = DefaultConfigurationBuilder::new()
+        .add_dotenv_file(&path)
+        .build();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    if let Err(ReloadError::Provider(errors)) = result {
+        assert_eq!(
+            errors[0].1.message(),
+            format!(
+                "Could not parse the .env file '{}'. The quoted value is not terminated. (Line: 2)",
+                path.display()
+            )
+        )
+    } else {
+        panic!("The expected error did not occur.")
+    }
+}
@@ -52,3 +52,31 @@ fn add_env_vars_should_translate_double_underscore_to_colon() {
     // assert
     assert_eq!(value.as_str(), expected);
 }
+
+#[test]
+fn add_env_vars_should_return_translated_child_keys() {
+    // arrange
+    set_var("MyApp_Db__Host", "localhost");
+    set_var("MyApp_Db__Port", "5432");
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_env_vars_with_prefix("MyApp_")
+        .build()
+        .unwrap();
+
+    // act
+    let children = config.children();
+    let mut keys: Vec<_> = config
+        .section("Db")
+        .children()
+        .iter()
+        .map(|c| c.key().to_owned())
+        .collect();
+
+    keys.sort();
+
+    // assert
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].key(), "Db");
+    assert_eq!(keys, vec!["Host", "Port"]);
+}
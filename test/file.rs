@@ -0,0 +1,99 @@
+use config::*;
+use std::collections::HashMap;
+use std::env::temp_dir;
+use std::fs::{remove_file, File};
+use std::io::Write;
+use std::path::Path;
+
+struct LineParser;
+
+impl FileConfigurationParser for LineParser {
+    fn parse(
+        &self,
+        content: &[u8],
+        _path: &Path,
+    ) -> Result<HashMap<String, (String, Value)>, String> {
+        String::from_utf8_lossy(content)
+            .lines()
+            .map(|line| match line.split_once('=') {
+                Some((key, value)) => Ok((
+                    key.to_uppercase(),
+                    (key.to_owned(), value.to_owned().into()),
+                )),
+                _ => Err(format!("Invalid line '{}'.", line)),
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn file_provider_should_load_data_from_parser() {
+    // arrange
+    let path = temp_dir().join("file_settings_1.txt");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(b"Service:Name=Demo\nService:Port=80")
+        .unwrap();
+
+    let mut provider = FileConfigurationProvider::new(path.clone().into(), LineParser);
+
+    // act
+    let result = provider.load();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert!(result.is_ok());
+    assert_eq!(provider.get("service:name").unwrap().as_str(), "Demo");
+}
+
+#[test]
+fn file_provider_should_report_path_when_parser_fails() {
+    // arrange
+    let path = temp_dir().join("file_settings_2.txt");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(b"Service:Name").unwrap();
+
+    let mut provider = FileConfigurationProvider::new(path.clone().into(), LineParser);
+
+    // act
+    let result = provider.load();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    if let Err(LoadError::File {
+        message,
+        path: file,
+    }) = result
+    {
+        assert_eq!(message, "Invalid line 'Service:Name'.");
+        assert_eq!(file, path);
+    } else {
+        panic!("{:?}", result)
+    }
+}
+
+#[test]
+fn file_provider_should_fail_if_required_file_does_not_exist() {
+    // arrange
+    let path = temp_dir().join("file_settings_3.txt");
+    let mut provider = FileConfigurationProvider::new(path.clone().into(), LineParser);
+
+    // act
+    let result = provider.load();
+
+    // assert
+    assert_eq!(
+        result.unwrap_err().message(),
+        format!(
+            "The configuration file '{}' was not found and is not optional.",
+            path.display()
+        )
+    );
+}
@@ -164,3 +164,117 @@ fn init_file_should_reload_when_changed() {
     assert_eq!(initial.as_str(), "true");
     assert_eq!(current.as_str(), "false");
 }
+
+#[test]
+fn add_ini_file_should_flatten_nested_section_names() {
+    // arrange
+    let path = temp_dir().join("test_settings_3.ini");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(b"; the primary database\n").unwrap();
+    file.write_all(b"[Database:Primary]\n").unwrap();
+    file.write_all(b"  host = localhost  \n").unwrap();
+    file.write_all(b"# the default port\n").unwrap();
+    file.write_all(b"port=5432\n").unwrap();
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_ini_file(&path)
+        .build()
+        .unwrap();
+
+    // act
+    let host = config.get("Database:Primary:host");
+    let port = config.section("Database").section("Primary").get("Port");
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert_eq!(host.unwrap().as_str(), "localhost");
+    assert_eq!(port.unwrap().as_str(), "5432");
+}
+
+#[test]
+fn add_ini_file_should_fail_if_key_is_duplicated() {
+    // arrange
+    let path = temp_dir().join("test_settings_4.ini");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(b"[Service]\n").unwrap();
+    file.write_all(b"Enabled=false\n").unwrap();
+    file.write_all(b"enabled=true\n").unwrap();
+
+    // act
+    let result = DefaultConfigurationBuilder::new()
+        .add_ini_file(&path)
+        .build();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    if let Err(ReloadError::Provider(errors)) = result {
+        assert_eq!(
+            errors[0].1.message(),
+            "A duplicate key 'enabled' was found in section 'Service'. (Line: 3)"
+        );
+    } else {
+        panic!("No error occurred.")
+    }
+}
+
+#[test]
+fn add_ini_file_should_accept_colon_as_separator() {
+    // arrange
+    let path = temp_dir().join("test_settings_5.ini");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(b"[Service]\n").unwrap();
+    file.write_all(b"Port: 80\n").unwrap();
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_ini_file(&path)
+        .build()
+        .unwrap();
+
+    // act
+    let port = config.section("Service").get("Port");
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert_eq!(port.unwrap().as_str(), "80");
+}
+
+#[test]
+fn add_ini_file_with_nested_keys_should_treat_colon_as_part_of_key() {
+    // arrange
+    let path = temp_dir().join("test_settings_6.ini");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(b"[Service]\n").unwrap();
+    file.write_all(b"Endpoint:Port=80\n").unwrap();
+    file.write_all(b"Endpoint:Host=localhost\n").unwrap();
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_ini_file_with_nested_keys(&path, true)
+        .build()
+        .unwrap();
+
+    // act
+    let section = config.section("Service").section("Endpoint");
+    let port = section.get("Port");
+    let host = section.get("Host");
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert_eq!(port.unwrap().as_str(), "80");
+    assert_eq!(host.unwrap().as_str(), "localhost");
+}
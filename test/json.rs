@@ -108,6 +108,34 @@ fn add_json_file_should_succeed_if_optional_file_does_not_exist() {
     assert_eq!(config.children().len(), 0);
 }
 
+#[test]
+fn add_json_file_should_fail_if_file_is_malformed() {
+    // arrange
+    let path = temp_dir().join("malformed_settings_1.json");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(br#"{"service": {"enabled": false}"#).unwrap();
+
+    // act
+    let result = DefaultConfigurationBuilder::new()
+        .add_json_file(&path)
+        .build();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    if let Err(ReloadError::Provider(errors)) = result {
+        assert!(errors[0]
+            .1
+            .message()
+            .starts_with("Could not parse the JSON file"));
+    } else {
+        panic!("No error occurred.")
+    }
+}
+
 #[test]
 fn simple_json_array_should_be_converted_to_key_value_pairs() {
     // arrange
@@ -288,6 +316,32 @@ fn json_arrays_should_be_merged() {
     assert_eq!(config.get("ip:3").unwrap().as_str(), "15.16.17.18");
 }
 
+#[test]
+fn to_json_should_rebuild_nested_objects_and_arrays() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[
+            ("service:name", "demo"),
+            ("service:ip:0", "1.2.3.4"),
+            ("service:ip:1", "5.6.7.8"),
+            ("service:ports:1", "80"),
+        ])
+        .build()
+        .unwrap();
+
+    // act
+    let json = config.to_json();
+
+    // assert
+    assert_eq!(
+        json,
+        json!({"service": {
+            "name": "demo",
+            "ip": ["1.2.3.4", "5.6.7.8"],
+            "ports": {"1": "80"}}})
+    );
+}
+
 #[test]
 fn json_file_should_reload_when_changed() {
     // arrange
@@ -367,3 +421,103 @@ fn json_file_should_reload_when_changed() {
     assert_eq!(initial.as_str(), "true");
     assert_eq!(current.as_str(), "false");
 }
+
+#[test]
+fn add_json_str_should_load_settings_from_json_document() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Db:Host", "localhost"), ("Db:Port", "5432")])
+        .add_json_str(r#"{"Db":{"Host":"db.local"},"Tags":["a","b"]}"#)
+        .build()
+        .unwrap();
+
+    // act
+    let host = config.get("Db:Host");
+    let port = config.get("Db:Port");
+    let tag = config.section("Tags").get("1");
+
+    // assert
+    assert_eq!(host.unwrap().as_str(), "db.local");
+    assert_eq!(port.unwrap().as_str(), "5432");
+    assert_eq!(tag.unwrap().as_str(), "b");
+}
+
+#[test]
+fn add_json_env_var_should_load_settings_from_environment_variable() {
+    // arrange
+    std::env::set_var("MORE_CONFIG_TEST_JSON_BLOB", r#"{"Db":{"Host":"x"}}"#);
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_json_env_var("MORE_CONFIG_TEST_JSON_BLOB")
+        .add_json_env_var("MORE_CONFIG_TEST_JSON_BLOB_MISSING")
+        .build()
+        .unwrap();
+
+    // act
+    let host = config.section("Db").get("Host");
+
+    // assert
+    assert_eq!(host.unwrap().as_str(), "x");
+}
+
+#[test]
+fn add_json_str_should_fail_if_json_is_invalid() {
+    // arrange
+    let json = "{\"Db\":";
+
+    // act
+    let result = DefaultConfigurationBuilder::new().add_json_str(json).build();
+
+    // assert
+    if let Err(ReloadError::Provider(errors)) = result {
+        assert!(errors[0]
+            .1
+            .message()
+            .starts_with("Could not parse the JSON document. Error on line number '1'"));
+    } else {
+        panic!("The expected error did not occur.")
+    }
+}
+
+#[test]
+fn reload_should_keep_previous_file_values_when_policy_is_fail_fast() {
+    // arrange
+    let path1 = temp_dir().join("fail_fast_settings_1.json");
+    let path2 = temp_dir().join("fail_fast_settings_2.json");
+
+    File::create(&path1)
+        .unwrap()
+        .write_all(br#"{"service": {"enabled": false}}"#)
+        .unwrap();
+    File::create(&path2)
+        .unwrap()
+        .write_all(br#"{"service": {"name": "test"}}"#)
+        .unwrap();
+
+    let mut config = DefaultConfigurationBuilder::new()
+        .reload_policy(ReloadPolicy::FailFast)
+        .add_json_file(&path1)
+        .add_json_file(&path2)
+        .build()
+        .unwrap();
+
+    File::create(&path1)
+        .unwrap()
+        .write_all(br#"{"service": {"enabled": true}}"#)
+        .unwrap();
+    File::create(&path2)
+        .unwrap()
+        .write_all(br#"{"service": {"name": "#)
+        .unwrap();
+
+    // act
+    let result = config.reload();
+
+    // assert
+    remove_file(&path1).ok();
+    remove_file(&path2).ok();
+
+    assert!(matches!(result, Err(ReloadError::Stopped { .. })));
+    assert_eq!(config.get("service:enabled").unwrap().as_str(), "false");
+    assert_eq!(config.get("service:name").unwrap().as_str(), "test");
+}
@@ -0,0 +1,147 @@
+use config::{ext::*, *};
+use std::collections::HashMap;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+use tokens::{ChangeToken, SharedChangeToken, SingleChangeToken};
+
+#[derive(Clone, Default)]
+struct CountingStore {
+    data: HashMap<String, String>,
+    fetches: Arc<AtomicUsize>,
+    loads: Arc<AtomicUsize>,
+    token: SharedChangeToken<SingleChangeToken>,
+}
+
+impl CountingStore {
+    fn new(data: &[(&str, &str)]) -> Self {
+        Self {
+            data: data
+                .iter()
+                .map(|(k, v)| (k.to_uppercase(), v.to_string()))
+                .collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl LazyConfigurationStore for CountingStore {
+    fn fetch(&self, key: &str) -> Option<String> {
+        self.fetches.fetch_add(1, Ordering::SeqCst);
+        self.data.get(&key.to_uppercase()).cloned()
+    }
+
+    fn child_keys(&self, parent_path: Option<&str>) -> Vec<String> {
+        let prefix = parent_path.map(|p| format!("{}:", p.to_uppercase()));
+
+        self.data
+            .keys()
+            .filter_map(|key| match prefix {
+                Some(ref prefix) => key.strip_prefix(prefix.as_str()),
+                _ => Some(key.as_str()),
+            })
+            .map(|key| key.split(':').next().unwrap().to_owned())
+            .collect()
+    }
+
+    fn reload_token(&self) -> Box<dyn ChangeToken> {
+        Box::new(self.token.clone())
+    }
+
+    fn load(&mut self) -> LoadResult {
+        self.loads.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+#[test]
+fn get_should_fetch_value_on_first_access_only() {
+    // arrange
+    let store = CountingStore::new(&[("Service:Name", "Demo")]);
+    let fetches = store.fetches.clone();
+    let config = DefaultConfigurationBuilder::new()
+        .add_lazy(store)
+        .build()
+        .unwrap();
+
+    // act
+    let first = config.get("Service:Name");
+    let second = config.get("service:name");
+    let missing = config.get("Missing");
+
+    config.get("Missing");
+
+    // assert
+    assert_eq!(first.unwrap().as_str(), "Demo");
+    assert_eq!(second.unwrap().as_str(), "Demo");
+    assert!(missing.is_none());
+    assert_eq!(fetches.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn get_should_fetch_value_again_after_reload() {
+    // arrange
+    let store = CountingStore::new(&[("Key", "Value")]);
+    let fetches = store.fetches.clone();
+    let loads = store.loads.clone();
+    let mut config = DefaultConfigurationBuilder::new()
+        .add_lazy(store)
+        .build()
+        .unwrap();
+
+    config.get("Key");
+
+    // act
+    config.reload().unwrap();
+    config.get("Key");
+
+    // assert
+    assert_eq!(loads.load(Ordering::SeqCst), 2);
+    assert_eq!(fetches.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn get_should_fetch_value_again_after_store_changes() {
+    // arrange
+    let store = CountingStore::new(&[("Key", "Value")]);
+    let fetches = store.fetches.clone();
+    let token = store.token.clone();
+    let config = DefaultConfigurationBuilder::new()
+        .add_lazy(store)
+        .build()
+        .unwrap();
+
+    config.get("Key");
+
+    // act
+    token.notify();
+    config.get("Key");
+
+    // assert
+    assert_eq!(fetches.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn children_should_enumerate_keys_from_store() {
+    // arrange
+    let store = CountingStore::new(&[("Service:Name", "Demo"), ("Service:Port", "80")]);
+    let fetches = store.fetches.clone();
+    let config = DefaultConfigurationBuilder::new()
+        .add_lazy(store)
+        .build()
+        .unwrap();
+
+    // act
+    let mut children: Vec<_> = config
+        .section("Service")
+        .children()
+        .iter()
+        .map(|section| section.key().to_owned())
+        .collect();
+
+    // assert
+    children.sort();
+    assert_eq!(children, vec!["NAME", "PORT"]);
+    assert_eq!(fetches.load(Ordering::SeqCst), 0);
+}
@@ -1,10 +1,18 @@
 #![cfg(test)]
 
 mod binder;
+mod chained;
 mod de;
 mod default;
+mod dotenv;
 mod env;
+mod file;
 mod ini;
 mod json;
+mod lazy;
+mod memory;
+mod prefixed;
 mod reload;
 mod xml;
+mod yaml;
+mod toml;
@@ -0,0 +1,58 @@
+use config::{ext::*, *};
+use std::sync::{
+    atomic::{AtomicU8, Ordering},
+    Arc,
+};
+
+#[test]
+fn set_should_update_value_at_runtime() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+    let provider = builder.add_in_memory_mutable(&[("Feature:Enabled", "false")]);
+    let config = builder.build().unwrap();
+
+    // act
+    provider.set("Feature:Enabled", "true");
+
+    // assert
+    assert_eq!(config.get("Feature:Enabled").unwrap().as_str(), "true");
+}
+
+#[test]
+fn set_many_should_add_child_keys_immediately() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+    let provider = builder.add_in_memory_mutable(&[("Feature:Enabled", "false")]);
+    let config = builder.build().unwrap();
+
+    // act
+    provider.set_many(&[("Feature:Name", "Demo"), ("Feature:Level", "2")]);
+
+    // assert
+    assert_eq!(config.section("Feature").children().len(), 3);
+}
+
+#[test]
+fn set_should_notify_reload_token() {
+    // arrange
+    let data = Arc::<AtomicU8>::default();
+    let mut builder = DefaultConfigurationBuilder::new();
+    let provider = builder.add_in_memory_mutable(&[("Key", "Value")]);
+    let config = builder.build().unwrap();
+    let _unused = config.reload_token().register(
+        Box::new(|state| {
+            state
+                .unwrap()
+                .downcast_ref::<AtomicU8>()
+                .unwrap()
+                .fetch_add(1, Ordering::SeqCst);
+        }),
+        Some(data.clone()),
+    );
+
+    // act
+    provider.set_many(&[("Key", "NewValue"), ("Other", "Value")]);
+
+    // assert
+    assert_eq!(data.load(Ordering::SeqCst), 1);
+}
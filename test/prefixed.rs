@@ -0,0 +1,105 @@
+use config::{ext::*, *};
+
+fn new_config() -> Box<dyn ConfigurationRoot> {
+    DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Logging:Level", "Warning")])
+        .add_prefixed(
+            "Infrastructure:Services",
+            Box::new(MemoryConfigurationSource::new(&[
+                ("DB:Host", "localhost"),
+                ("DB:Port", "5432"),
+                ("Cache", "redis"),
+            ])),
+        )
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn get_should_return_value_under_prefix() {
+    // arrange
+    let config = new_config();
+
+    // act
+    let host = config.get("infrastructure:services:DB:Host");
+
+    // assert
+    assert_eq!(host.unwrap().as_str(), "localhost");
+    assert!(config.get("DB:Host").is_none());
+}
+
+#[test]
+fn children_should_include_prefix_segments() {
+    // arrange
+    let config = new_config();
+
+    // act
+    let root: Vec<_> = config.children().iter().map(|c| c.key().to_owned()).collect();
+    let infrastructure: Vec<_> = config
+        .section("Infrastructure")
+        .children()
+        .iter()
+        .map(|c| c.key().to_owned())
+        .collect();
+
+    // assert
+    assert!(root.contains(&"Infrastructure".to_owned()));
+    assert!(root.contains(&"Logging".to_owned()));
+    assert_eq!(infrastructure, vec!["Services"]);
+}
+
+#[test]
+fn children_should_return_inner_keys_at_prefix_boundary() {
+    // arrange
+    let config = new_config();
+
+    // act
+    let mut services: Vec<_> = config
+        .section("Infrastructure:Services")
+        .children()
+        .iter()
+        .map(|c| c.key().to_owned())
+        .collect();
+    let db = config.section("Infrastructure:Services:DB").children();
+
+    services.sort();
+
+    // assert
+    assert_eq!(services, vec!["Cache", "DB"]);
+    assert_eq!(db.len(), 2);
+    assert_eq!(config.section("Infrastructure:Servic").children().len(), 0);
+}
+
+#[test]
+fn get_should_return_value_when_prefix_is_empty() {
+    // arrange
+    let config = DefaultConfigurationBuilder::new()
+        .add_prefixed(":", Box::new(MemoryConfigurationSource::new(&[("Key", "Value")])))
+        .build()
+        .unwrap();
+
+    // act
+    let value = config.get("Key");
+
+    // assert
+    assert_eq!(value.unwrap().as_str(), "Value");
+}
+
+#[test]
+fn children_should_not_include_prefix_segments_when_provider_is_empty() {
+    // arrange
+    let empty: [(&str, &str); 0] = [];
+    let config = DefaultConfigurationBuilder::new()
+        .add_in_memory(&[("Logging:Level", "Warning")])
+        .add_prefixed("App:Settings", Box::new(MemoryConfigurationSource::new(&empty)))
+        .build()
+        .unwrap();
+
+    // act
+    let children = config.children();
+
+    // assert
+    assert_eq!(children.len(), 1);
+    assert!(!config.section("App").exists());
+    assert!(config.get_required_section("App:Settings").is_err());
+}
@@ -24,6 +24,7 @@ impl Trigger {
 struct ReloadableConfigProvider {
     counter: u8,
     value: Value,
+    staged: Option<Value>,
     trigger: Rc<Trigger>,
 }
 
@@ -32,6 +33,7 @@ impl ReloadableConfigProvider {
         Self {
             counter: 0,
             value: Value::new("0".into()),
+            staged: None,
             trigger,
         }
     }
@@ -56,8 +58,26 @@ impl ConfigurationProvider for ReloadableConfigProvider {
         Ok(())
     }
 
-    fn child_keys(&self, earlier_keys: &mut Vec<String>, _parent_path: Option<&str>) {
-        earlier_keys.push("Test".into());
+    fn stage(&mut self) -> LoadResult {
+        self.counter += 1;
+        self.staged = Some(self.counter.to_string().into());
+        Ok(())
+    }
+
+    fn commit(&mut self) {
+        if let Some(value) = self.staged.take() {
+            self.value = value;
+        }
+    }
+
+    fn discard(&mut self) {
+        self.staged = None;
+    }
+
+    fn child_keys(&self, earlier_keys: &mut Vec<String>, parent_path: Option<&str>) {
+        if parent_path.is_none() {
+            earlier_keys.push("Test".into());
+        }
     }
 }
 
@@ -78,6 +98,39 @@ impl ConfigurationSource for ReloadableConfigSource {
     }
 }
 
+struct FailingConfigProvider {
+    loaded: bool,
+}
+
+impl ConfigurationProvider for FailingConfigProvider {
+    fn name(&self) -> &str {
+        "Failing"
+    }
+
+    fn get(&self, _key: &str) -> Option<Value> {
+        None
+    }
+
+    fn load(&mut self) -> LoadResult {
+        if self.loaded {
+            Err(LoadError::Generic("Reload failed.".into()))
+        } else {
+            self.loaded = true;
+            Ok(())
+        }
+    }
+
+    fn child_keys(&self, _earlier_keys: &mut Vec<String>, _parent_path: Option<&str>) {}
+}
+
+struct FailingConfigSource;
+
+impl ConfigurationSource for FailingConfigSource {
+    fn build(&self, _builder: &dyn ConfigurationBuilder) -> Box<dyn ConfigurationProvider> {
+        Box::new(FailingConfigProvider { loaded: false })
+    }
+}
+
 #[test]
 fn reload_should_load_providers() {
     // arrange
@@ -150,3 +203,135 @@ fn reload_token_should_indicate_change_after_provider_change() {
     // assert
     assert_eq!(data.load(Ordering::SeqCst), 1);
 }
+
+#[test]
+fn reload_token_should_indicate_each_provider_change() {
+    // arrange
+    let trigger = Rc::new(Trigger::default());
+    let data = Arc::<AtomicU8>::default();
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(ReloadableConfigSource::new(trigger.clone())));
+
+    let root = builder.build().unwrap();
+    let callback = |state: Option<Arc<dyn std::any::Any + Send + Sync>>| {
+        state
+            .unwrap()
+            .downcast_ref::<AtomicU8>()
+            .unwrap()
+            .fetch_add(1, Ordering::SeqCst);
+    };
+    let _first = root
+        .reload_token()
+        .register(Box::new(callback), Some(data.clone()));
+
+    trigger.fire();
+
+    let token = root.reload_token();
+    let _second = token.register(Box::new(callback), Some(data.clone()));
+
+    // act
+    trigger.fire();
+
+    // assert
+    assert_eq!(data.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn reload_should_stop_at_first_error_when_policy_is_fail_fast() {
+    // arrange
+    let data = Arc::<AtomicU8>::default();
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.reload_policy(ReloadPolicy::FailFast);
+    builder.add(Box::new(ReloadableConfigSource::default()));
+    builder.add(Box::new(FailingConfigSource));
+    builder.add(Box::new(ReloadableConfigSource::default()));
+
+    let mut root = builder.build().unwrap();
+    let _unused = root.reload_token().register(
+        Box::new(|state| {
+            state
+                .unwrap()
+                .downcast_ref::<AtomicU8>()
+                .unwrap()
+                .store(1, Ordering::SeqCst)
+        }),
+        Some(data.clone()),
+    );
+
+    // act
+    let result = root.reload();
+
+    // assert
+    if let Err(ReloadError::Stopped { loaded, error }) = result {
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(&error.0, "Failing");
+    } else {
+        panic!("{:?}", result)
+    }
+
+    assert_eq!(root.get("Test").unwrap().as_str(), "1");
+    assert_eq!(data.load(Ordering::SeqCst), 0);
+    assert!(!root.reload_token().changed());
+}
+
+#[test]
+fn reload_should_keep_previous_values_of_earlier_providers_when_policy_is_fail_fast() {
+    // arrange
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.reload_policy(ReloadPolicy::FailFast);
+    builder.add(Box::new(ReloadableConfigSource::default()));
+    builder.add(Box::new(FailingConfigSource));
+
+    let mut root = builder.build().unwrap();
+
+    // act
+    let result = root.reload();
+
+    // assert
+    if let Err(ReloadError::Stopped { loaded, .. }) = result {
+        assert_eq!(loaded.len(), 1);
+    } else {
+        panic!("{:?}", result)
+    }
+
+    assert_eq!(root.get("Test").unwrap().as_str(), "1");
+    assert_eq!(root.children().len(), 1);
+}
+
+#[test]
+fn reload_should_signal_change_and_report_errors_by_default() {
+    // arrange
+    let data = Arc::<AtomicU8>::default();
+    let mut builder = DefaultConfigurationBuilder::new();
+
+    builder.add(Box::new(FailingConfigSource));
+    builder.add(Box::new(ReloadableConfigSource::default()));
+
+    let mut root = builder.build().unwrap();
+    let _unused = root.reload_token().register(
+        Box::new(|state| {
+            state
+                .unwrap()
+                .downcast_ref::<AtomicU8>()
+                .unwrap()
+                .store(1, Ordering::SeqCst)
+        }),
+        Some(data.clone()),
+    );
+
+    // act
+    let result = root.reload();
+
+    // assert
+    if let Err(ReloadError::Provider(errors)) = result {
+        assert_eq!(&errors[0].0, "Failing");
+    } else {
+        panic!("{:?}", result)
+    }
+
+    assert_eq!(root.get("Test").unwrap().as_str(), "2");
+    assert_eq!(data.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,201 @@
+use config::{ext::*, *};
+use std::env::temp_dir;
+use std::fs::{remove_file, File};
+use std::io::Write;
+use std::path::PathBuf;
+
+#[test]
+fn add_toml_file_should_load_settings_from_file() {
+    // arrange
+    let path = temp_dir().join("test_settings_1.toml");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(
+        br#"# service settings
+title = "Demo # 1"
+
+[service]
+enabled = true
+port = 0x1F90
+retries = 1_000
+ratio = +1.5e3
+hosts = [
+  "localhost",
+  'example.com', # trailing comma
+]
+labels = { tier = "web", "zone" = "us-east" }
+connection.timeout = 30
+
+[service.limits]
+max = inf
+
+[[service.endpoints]]
+path = "/health"
+methods = ["GET", "HEAD"]
+
+[[service.endpoints]]
+path = "/api"
+released = 1979-05-27 07:32:00Z
+"#,
+    )
+    .unwrap();
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_toml_file(&path)
+        .build()
+        .unwrap();
+    let section = config.section("Service");
+
+    // act
+    let title = config.get("Title");
+    let enabled = section.get("Enabled");
+    let port = section.get("Port");
+    let retries = section.get("Retries");
+    let ratio = section.get("Ratio");
+    let host = section.get("Hosts:1");
+    let zone = section.get("Labels:Zone");
+    let timeout = section.get("Connection:Timeout");
+    let max = section.get("Limits:Max");
+    let path_1 = section.get("Endpoints:1:Path");
+    let method = section.get("Endpoints:0:Methods:1");
+    let released = section.get("Endpoints:1:Released");
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert_eq!(title.unwrap().as_str(), "Demo # 1");
+    assert_eq!(enabled.unwrap().as_str(), "true");
+    assert_eq!(port.unwrap().as_str(), "8080");
+    assert_eq!(retries.unwrap().as_str(), "1000");
+    assert_eq!(ratio.unwrap().as_str(), "1500");
+    assert_eq!(host.unwrap().as_str(), "example.com");
+    assert_eq!(zone.unwrap().as_str(), "us-east");
+    assert_eq!(timeout.unwrap().as_str(), "30");
+    assert_eq!(max.unwrap().as_str(), "inf");
+    assert_eq!(path_1.unwrap().as_str(), "/api");
+    assert_eq!(method.unwrap().as_str(), "HEAD");
+    assert_eq!(released.unwrap().as_str(), "1979-05-27T07:32:00Z");
+}
+
+#[test]
+fn add_toml_file_should_load_multiline_strings() {
+    // arrange
+    let path = temp_dir().join("test_settings_2.toml");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(
+        b"script = '''\necho one\n# not a comment\n'''\nsummary = \"\"\"\nfirst \\\n    second\\tthird\\u00E9\"\"\"\n",
+    )
+    .unwrap();
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_toml_file(&path)
+        .build()
+        .unwrap();
+
+    // act
+    let script = config.get("Script");
+    let summary = config.get("Summary");
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert_eq!(script.unwrap().as_str(), "echo one\n# not a comment\n");
+    assert_eq!(summary.unwrap().as_str(), "first second\tthird\u{e9}");
+}
+
+#[test]
+fn add_toml_file_should_fail_if_file_does_not_exist() {
+    // arrange
+    let path = PathBuf::from(r"C:\fake\settings.toml");
+
+    // act
+    let result = DefaultConfigurationBuilder::new()
+        .add_toml_file(&path)
+        .build();
+
+    // assert
+    if let Err(ReloadError::Provider(errors)) = result {
+        assert_eq!(
+            errors[0].1.message(),
+            r"The configuration file 'C:\fake\settings.toml' was not found and is not optional."
+        )
+    } else {
+        panic!("The expected error did not occur.")
+    }
+}
+
+#[test]
+fn add_toml_file_should_succeed_if_optional_file_does_not_exist() {
+    // arrange
+    let path = PathBuf::from(r"C:\fake\settings.toml");
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_toml_file(FileSource::optional(&path))
+        .build()
+        .unwrap();
+
+    // assert
+    assert_eq!(config.children().len(), 0);
+}
+
+#[test]
+fn add_toml_file_should_fail_if_key_is_defined_more_than_once() {
+    // arrange
+    let path = temp_dir().join("malformed_settings_1.toml");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(b"[service]\nport = 80\nport = 8080\n")
+        .unwrap();
+
+    // act
+    let result = DefaultConfigurationBuilder::new()
+        .add_toml_file(&path)
+        .build();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    if let Err(ReloadError::Provider(errors)) = result {
+        let message = errors[0].1.message();
+        assert!(message.starts_with("Could not parse the TOML file"));
+        assert!(message.ends_with("duplicate key (Line: 3)"));
+    } else {
+        panic!("No error occurred.")
+    }
+}
+
+#[test]
+fn add_toml_file_should_fail_if_file_is_malformed() {
+    // arrange
+    let path = temp_dir().join("malformed_settings_2.toml");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(b"[service]\nenabled = false\nport = 80 81\n")
+        .unwrap();
+
+    // act
+    let result = DefaultConfigurationBuilder::new()
+        .add_toml_file(&path)
+        .build();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    if let Err(ReloadError::Provider(errors)) = result {
+        let message = errors[0].1.message();
+        assert!(message.starts_with("Could not parse the TOML file"));
+        assert!(message.ends_with("(Line: 3)"));
+    } else {
+        panic!("No error occurred.")
+    }
+}
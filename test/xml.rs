@@ -743,6 +743,68 @@ fn load_should_fail_when_key_is_duplicated() {
     }
 }
 
+#[test]
+fn add_element_should_map_key_and_value_attributes_to_setting() {
+    // arrange
+    let xml = concat!(
+        "<configuration>\n",
+        " <appSettings>\n",
+        "  <add key='Theme' value='Dark' />\n",
+        "  <add key='PageSize' value='25' />\n",
+        " </appSettings>\n",
+        "</configuration>"
+    );
+    let path = temp_dir().join("test_settings_16.xml");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(xml.to_string().as_bytes()).unwrap();
+
+    let _file = TempFile(path.clone());
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_xml_file(&path)
+        .build()
+        .unwrap();
+
+    // assert
+    assert_eq!(config.get("appSettings:Theme").unwrap().as_str(), "Dark");
+    assert_eq!(config.get("appSettings:PageSize").unwrap().as_str(), "25");
+    assert!(config.get("appSettings:add:0:key").is_none());
+}
+
+#[test]
+fn load_should_fail_when_xml_is_malformed() {
+    // arrange
+    let xml = concat!(
+        "<settings>\n",
+        " <Data>\n",
+        "  <Value>42</Data>\n",
+        "</settings>"
+    );
+    let path = temp_dir().join("test_settings_17.xml");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(xml.to_string().as_bytes()).unwrap();
+
+    let _file = TempFile(path.clone());
+
+    // act
+    let result = DefaultConfigurationBuilder::new()
+        .add_xml_file(&path)
+        .build();
+
+    // assert
+    if let Err(ReloadError::Provider(errors)) = result {
+        assert!(errors[0]
+            .1
+            .message()
+            .starts_with(&format!("Could not parse the XML file '{}'.", path.display())));
+    } else {
+        panic!("No error occurred.")
+    }
+}
+
 #[test]
 fn xml_file_should_reload_when_changed() {
     // arrange
@@ -0,0 +1,201 @@
+use config::{ext::*, *};
+use std::env::temp_dir;
+use std::fs::{remove_file, File};
+use std::io::Write;
+use std::path::PathBuf;
+
+#[test]
+fn add_yaml_file_should_load_settings_from_file() {
+    // arrange
+    let path = temp_dir().join("test_settings_1.yaml");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(
+        br#"---
+# service settings
+service:
+  name: "Demo # 1"
+  enabled: True
+  port: 0x1F90
+  timeout: ~
+  hosts:
+    - localhost
+    - 'example.com'
+  endpoints:
+    - path: /health
+      methods: [GET, HEAD]
+    - path: /api
+      methods: [POST]
+  labels: {tier: web, zone: "us-east"}
+"#,
+    )
+    .unwrap();
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_yaml_file(&path)
+        .build()
+        .unwrap();
+    let section = config.section("Service");
+
+    // act
+    let name = section.get("Name");
+    let enabled = section.get("Enabled");
+    let port = section.get("Port");
+    let timeout = section.get("Timeout");
+    let host = section.get("Hosts:1");
+    let path_1 = section.get("Endpoints:1:Path");
+    let method = section.get("Endpoints:0:Methods:1");
+    let zone = section.get("Labels:Zone");
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert_eq!(name.unwrap().as_str(), "Demo # 1");
+    assert_eq!(enabled.unwrap().as_str(), "true");
+    assert_eq!(port.unwrap().as_str(), "8080");
+    assert_eq!(timeout.unwrap().as_str(), "");
+    assert_eq!(host.unwrap().as_str(), "example.com");
+    assert_eq!(path_1.unwrap().as_str(), "/api");
+    assert_eq!(method.unwrap().as_str(), "HEAD");
+    assert_eq!(zone.unwrap().as_str(), "us-east");
+}
+
+#[test]
+fn add_yaml_file_should_expand_anchors_and_aliases() {
+    // arrange
+    let path = temp_dir().join("test_settings_2.yaml");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(
+        br#"defaults: &defaults
+  adapter: postgres
+  host: localhost
+development:
+  <<: *defaults
+  database: dev
+production:
+  <<: *defaults
+  host: db.example.com
+admins:
+  - &admin root
+  - *admin
+"#,
+    )
+    .unwrap();
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_yaml_file(&path)
+        .build()
+        .unwrap();
+
+    // act
+    let adapter = config.get("Development:Adapter");
+    let database = config.get("Development:Database");
+    let host = config.get("Production:Host");
+    let admin = config.get("Admins:1");
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert_eq!(adapter.unwrap().as_str(), "postgres");
+    assert_eq!(database.unwrap().as_str(), "dev");
+    assert_eq!(host.unwrap().as_str(), "db.example.com");
+    assert_eq!(admin.unwrap().as_str(), "root");
+}
+
+#[test]
+fn add_yaml_file_should_load_block_scalars() {
+    // arrange
+    let path = temp_dir().join("test_settings_3.yml");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(
+        b"script: |\n  echo one\n  # not a comment\n\nsummary: >-\n  first\n  second\n\n  third\n",
+    )
+    .unwrap();
+
+    let config = DefaultConfigurationBuilder::new()
+        .add_yaml_file(&path)
+        .build()
+        .unwrap();
+
+    // act
+    let script = config.get("Script");
+    let summary = config.get("Summary");
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    assert_eq!(script.unwrap().as_str(), "echo one\n# not a comment\n");
+    assert_eq!(summary.unwrap().as_str(), "first second\nthird");
+}
+
+#[test]
+fn add_yaml_file_should_fail_if_file_does_not_exist() {
+    // arrange
+    let path = PathBuf::from(r"C:\fake\settings.yaml");
+
+    // act
+    let result = DefaultConfigurationBuilder::new()
+        .add_yaml_file(&path)
+        .build();
+
+    // assert
+    if let Err(ReloadError::Provider(errors)) = result {
+        assert_eq!(
+            errors[0].1.message(),
+            r"The configuration file 'C:\fake\settings.yaml' was not found and is not optional."
+        )
+    } else {
+        panic!("The expected error did not occur.")
+    }
+}
+
+#[test]
+fn add_yaml_file_should_succeed_if_optional_file_does_not_exist() {
+    // arrange
+    let path = PathBuf::from(r"C:\fake\settings.yaml");
+
+    // act
+    let config = DefaultConfigurationBuilder::new()
+        .add_yaml_file(FileSource::optional(&path))
+        .build()
+        .unwrap();
+
+    // assert
+    assert_eq!(config.children().len(), 0);
+}
+
+#[test]
+fn add_yaml_file_should_fail_if_file_is_malformed() {
+    // arrange
+    let path = temp_dir().join("malformed_settings_1.yaml");
+    let mut file = File::create(&path).unwrap();
+
+    file.write_all(b"service:\n  enabled: false\n    port: 80\n")
+        .unwrap();
+
+    // act
+    let result = DefaultConfigurationBuilder::new()
+        .add_yaml_file(&path)
+        .build();
+
+    // assert
+    if path.exists() {
+        remove_file(&path).ok();
+    }
+
+    if let Err(ReloadError::Provider(errors)) = result {
+        let message = errors[0].1.message();
+        assert!(message.starts_with("Could not parse the YAML file"));
+        assert!(message.ends_with("(Line: 3)"));
+    } else {
+        panic!("No error occurred.")
+    }
+}